@@ -0,0 +1,119 @@
+//! Compares the ASCII fast path of the string transforms with mapping the same text a char at a
+//! time, which is what the transforms did before it was added.
+//!
+//! Run with `cargo bench --bench ascii`.
+use std::fmt::Write;
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use unicode_titlecase::tr_az::{to_lowercase_tr_or_az, StrTrAzCasing};
+use unicode_titlecase::{StrCaseFold, StrTitleCase, TitleCase, TitleCaseWriter};
+
+const ASCII: &str = "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG. Pack my box with five dozen liquor \
+                     jugs! How vexingly quick daft zebras jump; 0123456789 (sphinx of black quartz).";
+
+const MIXED: &str = "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG. Ο ΓΡΗΓΟΡΟΣ ΚΑΦΕ ΑΛΕΠΟΥΣ. Çok \
+                     HIZLI kahverengi TİLKİ. Straße, ÆSIR, ĲSSELMEER and the ﬄ ligature.";
+
+/// The texts to benchmark with, repeated to a few KiB so the per-call overhead does not dominate.
+fn texts() -> [(&'static str, String); 2] {
+    [("ascii", ASCII.repeat(32)), ("mixed", MIXED.repeat(32))]
+}
+
+/// Titlecases the first char and lowercases the rest a char at a time.
+fn titlecase_lower_rest_by_char(s: &str) -> String {
+    let mut chars = s.chars();
+    let mut result = String::with_capacity(s.len());
+    result.extend(chars.next().into_iter().flat_map(TitleCase::to_titlecase));
+    result.extend(chars.flat_map(char::to_lowercase));
+    result
+}
+
+fn titlecase_lower_rest(c: &mut Criterion) {
+    let mut group = c.benchmark_group("to_titlecase_lower_rest");
+    for (name, text) in texts() {
+        group.throughput(Throughput::Bytes(text.len() as u64));
+        group.bench_with_input(BenchmarkId::new("fast_path", name), &text, |b, text| {
+            b.iter(|| black_box(text.as_str()).to_titlecase_lower_rest());
+        });
+        group.bench_with_input(BenchmarkId::new("char_by_char", name), &text, |b, text| {
+            b.iter(|| titlecase_lower_rest_by_char(black_box(text)));
+        });
+    }
+    group.finish();
+}
+
+fn lowercase_tr_az(c: &mut Criterion) {
+    let mut group = c.benchmark_group("to_lowercase_tr_az");
+    for (name, text) in texts() {
+        group.throughput(Throughput::Bytes(text.len() as u64));
+        group.bench_with_input(BenchmarkId::new("fast_path", name), &text, |b, text| {
+            b.iter(|| black_box(text.as_str()).to_lowercase_tr_az());
+        });
+        group.bench_with_input(BenchmarkId::new("char_by_char", name), &text, |b, text| {
+            b.iter(|| {
+                black_box(text.as_str())
+                    .chars()
+                    .map(to_lowercase_tr_or_az)
+                    .collect::<String>()
+            });
+        });
+    }
+    group.finish();
+}
+
+fn case_fold(c: &mut Criterion) {
+    let mut group = c.benchmark_group("to_case_folded");
+    for (name, text) in texts() {
+        group.throughput(Throughput::Bytes(text.len() as u64));
+        group.bench_with_input(BenchmarkId::new("fast_path", name), &text, |b, text| {
+            b.iter(|| black_box(text.as_str()).to_case_folded());
+        });
+        group.bench_with_input(BenchmarkId::new("char_by_char", name), &text, |b, text| {
+            b.iter(|| {
+                black_box(text.as_str())
+                    .chars()
+                    .flat_map(|c| {
+                        unicode_titlecase::fold_case(c)
+                            .into_iter()
+                            .filter(|&f| f != '\0')
+                    })
+                    .collect::<String>()
+            });
+        });
+    }
+    group.finish();
+}
+
+fn writer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("TitleCaseWriter::new_lower_rest");
+    for (name, text) in texts() {
+        group.throughput(Throughput::Bytes(text.len() as u64));
+        group.bench_with_input(BenchmarkId::new("fast_path", name), &text, |b, text| {
+            b.iter(|| {
+                let mut writer = TitleCaseWriter::new_lower_rest(String::with_capacity(text.len()));
+                writer.write_str(black_box(text)).unwrap();
+                writer.finish().unwrap()
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("char_by_char", name), &text, |b, text| {
+            b.iter(|| {
+                let mut writer = TitleCaseWriter::new_lower_rest(String::with_capacity(text.len()));
+                for c in black_box(text.as_str()).chars() {
+                    writer.write_char(c).unwrap();
+                }
+                writer.finish().unwrap()
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    titlecase_lower_rest,
+    lowercase_tr_az,
+    case_fold,
+    writer
+);
+criterion_main!(benches);
@@ -0,0 +1,84 @@
+//! Compares looking up titlecase mappings in the trie with a binary search of the sorted table
+//! that the trie replaced.
+//!
+//! Run with `cargo bench --bench lookup`.
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use unicode_titlecase::{changes_when_titlecased, to_titlecase};
+
+include!(concat!(env!("OUT_DIR"), "/titlecase_table.rs"));
+
+fn binary_search(c: char) -> [char; 3] {
+    TITLECASE_TABLE
+        .binary_search_by(|&(key, _)| key.cmp(&c))
+        .map_or([c, '\0', '\0'], |index| TITLECASE_TABLE[index].1)
+}
+
+/// The chars to look up: a mix of scripts and the whole of the Basic Multilingual Plane.
+fn inputs() -> [(&'static str, Vec<char>); 2] {
+    let text = "The quick brown fox. Ο γρήγορος καφέ αλεπού. Быстрая лиса. ǆungla ﬄ ΐ 速い狐";
+    [
+        ("text", text.chars().collect()),
+        ("bmp", ('\0'..='\u{FFFF}').collect()),
+    ]
+}
+
+fn lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("to_titlecase");
+    for (name, chars) in inputs() {
+        group.throughput(Throughput::Elements(chars.len() as u64));
+        group.bench_with_input(BenchmarkId::new("trie", name), &chars, |b, chars| {
+            b.iter(|| {
+                chars.iter().for_each(|&c| {
+                    black_box(to_titlecase(black_box(c)));
+                });
+            });
+        });
+        group.bench_with_input(
+            BenchmarkId::new("binary_search", name),
+            &chars,
+            |b, chars| {
+                b.iter(|| {
+                    chars.iter().for_each(|&c| {
+                        black_box(binary_search(black_box(c)));
+                    });
+                });
+            },
+        );
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("changes_when_titlecased");
+    for (name, chars) in inputs() {
+        group.throughput(Throughput::Elements(chars.len() as u64));
+        group.bench_with_input(BenchmarkId::new("trie", name), &chars, |b, chars| {
+            b.iter(|| {
+                black_box(chars)
+                    .iter()
+                    .filter(|&&c| changes_when_titlecased(c))
+                    .count()
+            });
+        });
+        group.bench_with_input(
+            BenchmarkId::new("binary_search", name),
+            &chars,
+            |b, chars| {
+                b.iter(|| {
+                    black_box(chars)
+                        .iter()
+                        .filter(|&&c| {
+                            TITLECASE_TABLE
+                                .binary_search_by(|&(key, _)| key.cmp(&c))
+                                .is_ok()
+                        })
+                        .count()
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, lookup);
+criterion_main!(benches);
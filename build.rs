@@ -1,9 +1,17 @@
 use std::{env, fs};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 
-/// This takes the Unicode files found in resources/ and converts them into the titlecase cable
-/// found in casing.rs.
+/// The number of low bits of a code point that index into a leaf block of the titlecase trie.
+const LEAF_BITS: u32 = 4;
+
+/// The number of bits of a code point above [`LEAF_BITS`] that index into a middle block of the
+/// titlecase trie. The remaining high bits index the top level.
+const MIDDLE_BITS: u32 = 5;
+
+/// This takes the Unicode files found in resources/ and converts them into the titlecase trie
+/// found in casing.rs, the character property tables found in properties.rs, the case folding
+/// tables found in folding.rs and the Greek letter table found in greek.rs.
 pub fn main() {
     println!("cargo:rerun-if-changed=resources/");
     println!("cargo:rerun-if-changed=src/");
@@ -11,7 +19,12 @@ pub fn main() {
     let in_path = Path::new(&env::var_os("CARGO_MANIFEST_DIR").unwrap()).join("resources");
     let sc_path = in_path.join("SpecialCasing.txt");
     let base_path = in_path.join("UnicodeData.txt");
+    let cf_path = in_path.join("CaseFolding.txt");
     let dest_path = Path::new(&out_dir).join("casing.rs");
+    let table_path = Path::new(&out_dir).join("titlecase_table.rs");
+    let properties_path = Path::new(&out_dir).join("properties.rs");
+    let folding_path = Path::new(&out_dir).join("folding.rs");
+    let greek_path = Path::new(&out_dir).join("greek.rs");
 
     let mut data: BTreeMap<char, [&str; 3]> = BTreeMap::new();
 
@@ -39,7 +52,7 @@ pub fn main() {
     base_file.lines().for_each(|line| {
         let mut l = line.split(';');
         let cp = l.next().unwrap();
-        if let Some(last_cp) = l.last().filter(|&last| !last.is_empty() && cp != last) {
+        if let Some(last_cp) = l.next_back().filter(|&last| !last.is_empty() && cp != last) {
             let cp = char::from_u32(u32::from_str_radix(cp, 16).unwrap()).unwrap();
             if let Some(old) = data.insert(cp, [last_cp, "0", "0"]) {
                 assert_eq!(old[0], last_cp, "For code point: {cp}");
@@ -59,9 +72,354 @@ pub fn main() {
         })
         .collect();
 
+    // The sorted table is only used by the tests, to check the trie against.
     fs::write(
-        dest_path,
+        table_path,
         format!("static TITLECASE_TABLE: &[(char, [char; 3])] = &[\n{lines}];"),
     )
     .unwrap();
+
+    fs::write(dest_path, titlecase_trie(&data)).unwrap();
+
+    let categories: HashMap<u32, &str> = base_file
+        .lines()
+        .map(|line| {
+            let mut l = line.split(';');
+            let cp = u32::from_str_radix(l.next().unwrap(), 16).unwrap();
+            (cp, l.nth(1).unwrap())
+        })
+        .collect();
+
+    // Cased is defined in Unicode 3.13 (D135) as the chars that are Lowercase, Uppercase or have a
+    // general category of Lt. Lowercase and Uppercase are the Ll and Lu chars plus those with the
+    // Other_Lowercase or Other_Uppercase property. PropList.txt is not in resources/ so those ranges
+    // are copied here by hand from the Unicode 16.0.0 PropList.txt, and must be checked against the
+    // new file whenever the files in resources/ are updated.
+    let other_cased: [(u32, u32); 31] = [
+        (0x00AA, 0x00AA),
+        (0x00BA, 0x00BA),
+        (0x02B0, 0x02B8),
+        (0x02C0, 0x02C1),
+        (0x02E0, 0x02E4),
+        (0x0345, 0x0345),
+        (0x037A, 0x037A),
+        (0x10FC, 0x10FC),
+        (0x1D2C, 0x1D6A),
+        (0x1D78, 0x1D78),
+        (0x1D9B, 0x1DBF),
+        (0x2071, 0x2071),
+        (0x207F, 0x207F),
+        (0x2090, 0x209C),
+        (0x2160, 0x217F),
+        (0x24B6, 0x24E9),
+        (0x2C7C, 0x2C7D),
+        (0xA69C, 0xA69D),
+        (0xA770, 0xA770),
+        (0xA7F2, 0xA7F4),
+        (0xA7F8, 0xA7F9),
+        (0xAB5C, 0xAB5F),
+        (0xAB69, 0xAB69),
+        (0x10780, 0x10780),
+        (0x10783, 0x10785),
+        (0x10787, 0x107B0),
+        (0x107B2, 0x107BA),
+        (0x1E030, 0x1E06D),
+        (0x1F130, 0x1F149),
+        (0x1F150, 0x1F169),
+        (0x1F170, 0x1F189),
+    ];
+    for cp in other_cased.iter().flat_map(|&(first, last)| first..=last) {
+        let category = categories.get(&cp).copied();
+        assert!(
+            category.is_some_and(|gc| !matches!(gc, "Ll" | "Lu" | "Lt")),
+            "Other_Lowercase or Other_Uppercase code point {cp:X} is unassigned or already cased"
+        );
+    }
+    let mut cased: Vec<u32> = base_file
+        .lines()
+        .filter(|line| matches!(line.split(';').nth(2), Some("Ll" | "Lu" | "Lt")))
+        .map(|line| u32::from_str_radix(line.split(';').next().unwrap(), 16).unwrap())
+        .chain(other_cased.iter().flat_map(|&(first, last)| first..=last))
+        .collect();
+    cased.sort_unstable();
+    cased.dedup();
+    let cased: String = cased
+        .iter()
+        .map(|cp| format!("'\\u{{{cp:X}}}',\n"))
+        .collect();
+
+    // Case_Ignorable is defined in Unicode 3.13 (D136) as the chars with a general category of
+    // Mn, Me, Cf, Lm or Sk plus the chars with a Word_Break of MidLetter, MidNumLet or
+    // Single_Quote. WordBreakProperty.txt is not in resources/ so those few chars are copied here
+    // by hand from the Unicode 16.0.0 WordBreakProperty.txt, and must be checked against the new file
+    // whenever the files in resources/ are updated.
+    let word_break_ignorable: [u32; 17] = [
+        0x0027, 0x002E, 0x003A, 0x00B7, 0x0387, 0x055F, 0x05F4, 0x2018, 0x2019, 0x2024, 0x2027,
+        0xFE13, 0xFE52, 0xFE55, 0xFF07, 0xFF0E, 0xFF1A,
+    ];
+    for cp in word_break_ignorable {
+        assert!(
+            categories.contains_key(&cp),
+            "Word_Break code point {cp:X} is unassigned"
+        );
+    }
+    let mut case_ignorable: Vec<u32> = base_file
+        .lines()
+        .filter(|line| {
+            matches!(
+                line.split(';').nth(2),
+                Some("Mn" | "Me" | "Cf" | "Lm" | "Sk")
+            )
+        })
+        .map(|line| u32::from_str_radix(line.split(';').next().unwrap(), 16).unwrap())
+        .chain(word_break_ignorable)
+        .collect();
+    case_ignorable.sort_unstable();
+    case_ignorable.dedup();
+    let case_ignorable: String = case_ignorable
+        .iter()
+        .map(|cp| format!("'\\u{{{cp:X}}}',\n"))
+        .collect();
+
+    let combining_classes: String = base_file
+        .lines()
+        .filter_map(|line| {
+            let mut l = line.split(';');
+            let cp = l.next().unwrap();
+            let ccc = l.nth(2).unwrap();
+            (ccc != "0").then(|| format!("('\\u{{{cp}}}', {ccc}),\n"))
+        })
+        .collect();
+
+    fs::write(
+        properties_path,
+        format!(
+            "static CASED_TABLE: &[char] = &[\n{cased}];\n\
+             static CASE_IGNORABLE_TABLE: &[char] = &[\n{case_ignorable}];\n\
+             static COMBINING_CLASS_TABLE: &[(char, u8)] = &[\n{combining_classes}];"
+        ),
+    )
+    .unwrap();
+
+    let mut full_folding: BTreeMap<char, [&str; 3]> = BTreeMap::new();
+    let mut simple_folding: BTreeMap<char, &str> = BTreeMap::new();
+    let mut turkic_folding: BTreeMap<char, &str> = BTreeMap::new();
+
+    let cf_file = fs::read_to_string(cf_path).unwrap();
+    cf_file
+        .lines()
+        .filter(|&s| !s.starts_with('#') && !s.is_empty())
+        .for_each(|line| {
+            let mut l = line.split("; ");
+            let code_point = l.next().unwrap();
+            let status = l.next().unwrap();
+            let mapping: Vec<&str> = l.next().unwrap().split_ascii_whitespace().collect();
+            let cp = char::from_u32(u32::from_str_radix(code_point, 16).unwrap()).unwrap();
+            match status {
+                "C" => {
+                    full_folding.insert(cp, [mapping[0], "0", "0"]);
+                    simple_folding.insert(cp, mapping[0]);
+                }
+                "F" => {
+                    let second = mapping.get(1).unwrap_or(&"0");
+                    let third = mapping.get(2).unwrap_or(&"0");
+                    full_folding.insert(cp, [mapping[0], second, third]);
+                }
+                "S" => {
+                    simple_folding.insert(cp, mapping[0]);
+                }
+                "T" => {
+                    turkic_folding.insert(cp, mapping[0]);
+                }
+                _ => panic!("Unknown case folding status {status} for code point: {cp}"),
+            }
+        });
+
+    let full_lines: String = full_folding
+        .iter()
+        .map(|(cp, cf)| {
+            format!(
+                "('\\u{{{:X}}}', ['\\u{{{}}}', '\\u{{{}}}', '\\u{{{}}}',]),\n",
+                *cp as u32, cf[0], cf[1], cf[2]
+            )
+        })
+        .collect();
+    let simple_lines = |folding: &BTreeMap<char, &str>| -> String {
+        folding
+            .iter()
+            .map(|(cp, cf)| format!("('\\u{{{:X}}}', '\\u{{{cf}}}'),\n", *cp as u32))
+            .collect()
+    };
+
+    fs::write(
+        folding_path,
+        format!(
+            "static CASE_FOLDING_TABLE: &[(char, [char; 3])] = &[\n{full_lines}];\n\
+             static SIMPLE_CASE_FOLDING_TABLE: &[(char, char)] = &[\n{}];\n\
+             static TURKIC_CASE_FOLDING_TABLE: &[(char, char)] = &[\n{}];",
+            simple_lines(&simple_folding),
+            simple_lines(&turkic_folding),
+        ),
+    )
+    .unwrap();
+
+    fs::write(greek_path, greek_letters(&base_file)).unwrap();
+}
+
+/// Generates the table of Greek letters that uppercase differently in the el locale: the vowels
+/// and every letter with a diacritic. Each is stored with the uppercase of its base letter and the
+/// flags of the diacritics its canonical decomposition carries, which are the constants of the
+/// same names in src/el.rs.
+fn greek_letters(base_file: &str) -> String {
+    let parse = |cp: &str| u32::from_str_radix(cp, 16).unwrap();
+    let mut decompositions: HashMap<u32, Vec<u32>> = HashMap::new();
+    let mut uppercase: HashMap<u32, u32> = HashMap::new();
+    for line in base_file.lines() {
+        let fields: Vec<&str> = line.split(';').collect();
+        let cp = parse(fields[0]);
+        if !fields[5].is_empty() && !fields[5].starts_with('<') {
+            decompositions.insert(cp, fields[5].split(' ').map(parse).collect());
+        }
+        if !fields[12].is_empty() {
+            uppercase.insert(cp, parse(fields[12]));
+        }
+    }
+    fn decompose(cp: u32, decompositions: &HashMap<u32, Vec<u32>>) -> Vec<u32> {
+        decompositions.get(&cp).map_or_else(
+            || vec![cp],
+            |parts| parts.iter().flat_map(|&part| decompose(part, decompositions)).collect(),
+        )
+    }
+
+    let mut lines = String::new();
+    for line in base_file.lines().filter(|line| line.split(';').nth(2).unwrap().starts_with('L')) {
+        let cp = parse(line.split(';').next().unwrap());
+        let decomposition = decompose(cp, &decompositions);
+        let upper = *uppercase.get(&decomposition[0]).unwrap_or(&decomposition[0]);
+        if !(0x0391..=0x03A9).contains(&upper) {
+            continue;
+        }
+        let mut flags = Vec::new();
+        if matches!(upper, 0x0391 | 0x0395 | 0x0397 | 0x0399 | 0x039F | 0x03A5 | 0x03A9) {
+            flags.push("VOWEL");
+        }
+        for mark in &decomposition[1..] {
+            let flag = match mark {
+                0x0300 | 0x0301 | 0x0342 => "ACCENT",
+                0x0308 => "DIALYTIKA",
+                0x0345 => "YPOGEGRAMMENI",
+                0x0304 | 0x0306 | 0x0313 | 0x0314 => "OTHER_DIACRITIC",
+                _ => panic!("Unknown Greek diacritic {mark:04X} for code point: {cp:04X}"),
+            };
+            if !flags.contains(&flag) {
+                flags.push(flag);
+            }
+        }
+        if !flags.is_empty() {
+            lines.push_str(&format!(
+                "('\\u{{{cp:X}}}', '\\u{{{upper:X}}}', {}),\n",
+                flags.join(" | ")
+            ));
+        }
+    }
+    format!("static GREEK_LETTER_TABLE: &[(char, char, u8)] = &[\n{lines}];")
+}
+
+/// Splits `values` into blocks of `1 << bits` entries and stores each distinct block once. Returns
+/// the block number of every block in order and the distinct blocks joined together.
+fn dedup_blocks(values: &[u8], bits: u32) -> (Vec<u8>, Vec<u8>) {
+    let mut numbers: HashMap<&[u8], u8> = HashMap::new();
+    let mut blocks = Vec::new();
+    let mut data = Vec::new();
+    for block in values.chunks(1 << bits) {
+        let next = u8::try_from(numbers.len()).expect("more than 256 distinct trie blocks");
+        let number = *numbers.entry(block).or_insert_with(|| {
+            data.extend_from_slice(block);
+            next
+        });
+        blocks.push(number);
+    }
+    (blocks, data)
+}
+
+/// Formats bytes as the elements of an array, 16 to a line.
+fn byte_lines(bytes: &[u8]) -> String {
+    bytes
+        .chunks(16)
+        .map(|line| {
+            let line: Vec<String> = line.iter().map(u8::to_string).collect();
+            format!("{},\n", line.join(", "))
+        })
+        .collect()
+}
+
+/// Generates the three-stage trie that maps every char to its titlecase mapping.
+///
+/// Most chars titlecase to a single char a small distance away, and runs of letters share the
+/// same distance, so a mapping to a single char is stored as an offset in `TITLECASE_OFFSETS`.
+/// The few mappings to several chars are stored in full in `TITLECASE_EXPANSIONS`. The trie maps
+/// a char to the index of its mapping in the offsets followed by the expansions, and index 0 is
+/// the offset 0 of every char that titlecases to itself.
+///
+/// The low [`LEAF_BITS`] of a code point index into a leaf block of mapping indices, the next
+/// [`MIDDLE_BITS`] into a middle block of leaf block numbers, and the high bits into the top level
+/// of middle block numbers. Identical blocks are stored once, which is what makes the trie small.
+fn titlecase_trie(data: &BTreeMap<char, [&str; 3]>) -> String {
+    let parse = |cp: &str| u32::from_str_radix(cp, 16).unwrap();
+    // The offsets of the single char mappings, sorted. Index 0 is left for the identity mapping, so
+    // the offset at `i` has the mapping index `i + 1`.
+    let mut offsets: Vec<i64> = Vec::new();
+    let mut expansions: Vec<[&str; 3]> = Vec::new();
+    for (&cp, tc) in data {
+        if tc[1] == "0" {
+            offsets.push(i64::from(parse(tc[0])) - i64::from(u32::from(cp)));
+        } else {
+            expansions.push(*tc);
+        }
+    }
+    offsets.sort_unstable();
+    offsets.dedup();
+    assert!(!offsets.contains(&0), "a titlecase mapping to itself");
+    expansions.sort_unstable();
+    expansions.dedup();
+
+    let end = u32::from(*data.keys().next_back().unwrap()) + 1;
+    let end = end.next_multiple_of(1 << (LEAF_BITS + MIDDLE_BITS));
+    let indices: Vec<u8> = (0..end)
+        .map(|cp| {
+            let index = match char::from_u32(cp).and_then(|c| data.get(&c)) {
+                None => 0,
+                Some(tc) if tc[1] == "0" => {
+                    let offset = i64::from(parse(tc[0])) - i64::from(cp);
+                    1 + offsets.binary_search(&offset).unwrap()
+                }
+                Some(tc) => 1 + offsets.len() + expansions.binary_search(tc).unwrap(),
+            };
+            u8::try_from(index).expect("more than 256 distinct titlecase mappings")
+        })
+        .collect();
+    let (leaf_numbers, leaves) = dedup_blocks(&indices, LEAF_BITS);
+    let (top, middle) = dedup_blocks(&leaf_numbers, MIDDLE_BITS);
+
+    let offset_lines: String = [0]
+        .iter()
+        .chain(&offsets)
+        .map(|offset| format!("{offset},\n"))
+        .collect();
+    let expansion_lines: String = expansions
+        .iter()
+        .map(|tc| format!("['\\u{{{}}}', '\\u{{{}}}', '\\u{{{}}}'],\n", tc[0], tc[1], tc[2]))
+        .collect();
+    format!(
+        "const TITLECASE_LEAF_BITS: usize = {LEAF_BITS};\n\
+         const TITLECASE_MIDDLE_BITS: usize = {MIDDLE_BITS};\n\
+         static TITLECASE_TOP: &[u8] = &[\n{}];\n\
+         static TITLECASE_MIDDLE: &[u8] = &[\n{}];\n\
+         static TITLECASE_LEAVES: &[u8] = &[\n{}];\n\
+         static TITLECASE_OFFSETS: &[i32] = &[\n{offset_lines}];\n\
+         static TITLECASE_EXPANSIONS: &[[char; 3]] = &[\n{expansion_lines}];",
+        byte_lines(&top),
+        byte_lines(&middle),
+        byte_lines(&leaves),
+    )
 }
@@ -0,0 +1,183 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::mem::size_of;
+#[cfg(feature = "alloc")]
+use core::ops::Range;
+
+/// The number of bytes checked at a time by [`ascii_prefix_len`].
+const WORD: usize = size_of::<usize>();
+
+/// The lowest bit of every byte of a word.
+const LOW_BITS: usize = usize::from_ne_bytes([0x01; WORD]);
+
+/// The highest bit of every byte of a word, which is only set in non-ASCII bytes.
+const HIGH_BITS: usize = usize::from_ne_bytes([0x80; WORD]);
+
+/// Returns true if any byte of the ASCII `word` is `byte`.
+fn contains_byte(word: usize, byte: u8) -> bool {
+    let x = word ^ (LOW_BITS * usize::from(byte));
+    x.wrapping_sub(LOW_BITS) & !x & HIGH_BITS != 0
+}
+
+/// Returns the length of the longest prefix of `s` that is all ASCII and holds none of the `special`
+/// bytes. The casing of these bytes needs no context and no table lookups, so callers map the prefix
+/// with the `make_ascii_` functions and only decode the chars after it. `special` lists the ASCII
+/// bytes with locale specific mappings, such as 'I' when lowercasing in TR/AZ.
+///
+/// The bytes are checked a word at a time until a word with a non-ASCII or special byte is found.
+pub(crate) fn ascii_prefix_len(s: &str, special: &[u8]) -> usize {
+    let bytes = s.as_bytes();
+    let mut len = 0;
+    for chunk in bytes.chunks_exact(WORD) {
+        let word = usize::from_ne_bytes(chunk.try_into().unwrap_or_default());
+        if word & HIGH_BITS != 0 || special.iter().any(|&b| contains_byte(word, b)) {
+            break;
+        }
+        len += WORD;
+    }
+    len + bytes[len..]
+        .iter()
+        .position(|&b| !b.is_ascii() || special.contains(&b))
+        .unwrap_or(bytes.len() - len)
+}
+
+/// Maps the chars of `s` in `range` and appends them to `out`. Runs of ASCII without any of the
+/// `special` bytes are copied and mapped in place with `ascii`, and the chars between them are
+/// passed to `map` with their range in `s`.
+#[cfg(feature = "alloc")]
+pub(crate) fn push_mapped(
+    out: &mut String,
+    s: &str,
+    range: Range<usize>,
+    special: &[u8],
+    ascii: fn(&mut str),
+    mut map: impl FnMut(&mut String, Range<usize>),
+) {
+    let mut i = range.start;
+    while i < range.end {
+        let ascii_end = i + ascii_prefix_len(&s[i..range.end], special);
+        let start = out.len();
+        out.push_str(&s[i..ascii_end]);
+        ascii(&mut out[start..]);
+        i = s[ascii_end..range.end]
+            .bytes()
+            .position(|b| b.is_ascii() && !special.contains(&b))
+            .map_or(range.end, |next| ascii_end + next);
+        if ascii_end < i {
+            map(out, ascii_end..i);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ascii_prefix_len;
+
+    #[cfg(feature = "alloc")]
+    const TEXTS: &[&str] = &[
+        "",
+        "hello WORLD, Hello World!",
+        "KIRMIZI I\u{0307}ZMIR istanbul İSTANBUL",
+        "ΟΔΟΣ ABC Σ. ΣΑΣ ΟΔΟΣ'S",
+        "Καλημέρα, Μάιος aή ή ᾳ",
+        "Երևան և ԵՐԵՎԱՆ",
+        "JI\u{0301} J\u{0303}Į\u{0301} I\u{0328}",
+        "i\u{0307}\u{0300}s ij j\u{0307}\u{0301}",
+        "Straße ǅ ﬄ AFFLUENT İI",
+    ];
+
+    #[test]
+    fn prefix_len() {
+        assert_eq!(ascii_prefix_len("", b""), 0);
+        assert_eq!(ascii_prefix_len("hello", b""), 5);
+        assert_eq!(ascii_prefix_len("hello wörld, this is not ascii", b""), 7);
+        assert_eq!(ascii_prefix_len("ΟΔΟΣ", b""), 0);
+        assert_eq!(ascii_prefix_len("KIRMIZI", b"I"), 1);
+        assert_eq!(ascii_prefix_len("kırmızı", b"I"), 1);
+        assert_eq!(ascii_prefix_len("ABCDEFGHIJKLMNOP", b"IJ"), 8);
+        assert_eq!(ascii_prefix_len("abcdefghijklmnopJ", b"IJ"), 16);
+        let s = "abcdefghijklmnopqrstuvwxyz0123456789.,;:é";
+        for start in 0..=40 {
+            assert_eq!(ascii_prefix_len(&s[start..], b""), 40 - start);
+            assert_eq!(ascii_prefix_len(&s[start..40], b""), 40 - start);
+            let semicolon = s[start..].find(';').unwrap_or(40 - start);
+            assert_eq!(ascii_prefix_len(&s[start..], b";"), semicolon);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn matches_char_by_char() {
+        use alloc::string::String;
+
+        use crate::el::push_uppercase_el;
+        use crate::fold::folder_in;
+        use crate::lt::{push_lowercase_lt, push_without_soft_dots};
+        use crate::tr_az::{push_lowercase_tr_az, to_uppercase_tr_or_az};
+        use crate::{push_lowercase, CaseMappingIter, Locale, StrCaseFold, StrLocaleCasing};
+
+        for &text in TEXTS {
+            let all = 0..text.len();
+            for locale in [
+                Locale::Root,
+                Locale::Turkish,
+                Locale::Azeri,
+                Locale::Lithuanian,
+                Locale::Dutch,
+                Locale::Irish,
+                Locale::Greek,
+                Locale::GermanCapitalSharpS,
+                Locale::Armenian,
+            ] {
+                let mut lower = String::new();
+                let mut upper = String::new();
+                match locale {
+                    Locale::Turkish | Locale::Azeri => {
+                        push_lowercase_tr_az(&mut lower, text, all.clone());
+                        upper.extend(text.chars().flat_map(to_uppercase_tr_or_az));
+                    }
+                    Locale::Lithuanian => {
+                        push_lowercase_lt(&mut lower, text, all.clone());
+                        push_without_soft_dots(&mut upper, text, all.clone(), char::to_uppercase);
+                    }
+                    Locale::GermanCapitalSharpS => {
+                        push_lowercase(&mut lower, text, all.clone(), char::to_lowercase);
+                        upper = text.replace('ß', "ẞ").to_uppercase();
+                    }
+                    Locale::Armenian => {
+                        push_lowercase(&mut lower, text, all.clone(), char::to_lowercase);
+                        upper = text.replace('և', "ԵՎ").to_uppercase();
+                    }
+                    Locale::Greek => {
+                        push_lowercase(&mut lower, text, all.clone(), char::to_lowercase);
+                        push_uppercase_el(&mut upper, text, all.clone());
+                    }
+                    _ => {
+                        push_lowercase(&mut lower, text, all.clone(), char::to_lowercase);
+                        upper.extend(text.chars().flat_map(char::to_uppercase));
+                    }
+                }
+                let fold = folder_in(locale);
+                let folded: String = text
+                    .chars()
+                    .flat_map(|c| CaseMappingIter::new(fold(c)))
+                    .collect();
+                assert_eq!(
+                    text.to_lowercase_in(locale),
+                    lower,
+                    "For {text:?} in {locale:?}"
+                );
+                assert_eq!(
+                    text.to_uppercase_in(locale),
+                    upper,
+                    "For {text:?} in {locale:?}"
+                );
+                assert_eq!(
+                    text.to_case_folded_in(locale),
+                    folded,
+                    "For {text:?} in {locale:?}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use core::fmt::{self, Display, Formatter, Write};
+
+use crate::{CaseTransform, TitleCaseWriter};
+
+/// The error returned when the transformed text does not fit in the buffer given to
+/// [`transform_into`] or one of the `_into` functions.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::to_titlecase_into;
+/// let mut buf = [0; 4];
+/// let error = to_titlecase_into("ﬄabc", &mut buf).unwrap_err();
+/// assert_eq!(error.required(), 6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BufferTooSmall {
+    required: usize,
+}
+
+impl BufferTooSmall {
+    /// Returns the length in bytes of the whole transformed text, which is the smallest buffer it
+    /// fits in.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::to_titlecase_lower_rest_into;
+    /// let mut buf = [0; 0];
+    /// let error = to_titlecase_lower_rest_into("ΟΔΟΣ", &mut buf).unwrap_err();
+    /// assert_eq!(error.required(), "Οδος".len());
+    /// ```
+    #[must_use]
+    pub fn required(&self) -> usize {
+        self.required
+    }
+}
+
+impl Display for BufferTooSmall {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "buffer too small, {} bytes required", self.required)
+    }
+}
+
+impl core::error::Error for BufferTooSmall {}
+
+/// Copies the text written to it into a byte slice. Text that does not fit is counted but not
+/// copied, so it never fails and `len` ends up as the length of all of the text. The copied text
+/// always ends on a char boundary.
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        // Once a str has not fit, `len` is past the end of the buffer and nothing more is copied.
+        if let Some(space) = self.buf.len().checked_sub(self.len) {
+            let mut fits = s.len().min(space);
+            while !s.is_char_boundary(fits) {
+                fits -= 1;
+            }
+            self.buf[self.len..self.len + fits].copy_from_slice(&s.as_bytes()[..fits]);
+        }
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Applies the transform to `s` and writes the UTF-8 result to the start of `buf` without
+/// allocating. Returns the number of bytes written.
+///
+/// # Errors
+/// Returns [`BufferTooSmall`] if the result does not fit in `buf`. The buffer then holds as much of
+/// the start of the result as fits, ending on a char boundary.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::{transform_into, CaseTransform};
+/// let mut buf = [0; 16];
+/// let len = transform_into("IŞIK", CaseTransform::LowercaseTrAz, &mut buf).unwrap();
+/// assert_eq!(core::str::from_utf8(&buf[..len]), Ok("ışık"));
+/// ```
+/// A result that does not fit is cut short:
+/// ```
+/// use unicode_titlecase::{transform_into, CaseTransform};
+/// let mut buf = [0; 3];
+/// assert!(transform_into("abcdef", CaseTransform::Titlecase, &mut buf).is_err());
+/// assert_eq!(&buf, b"Abc");
+/// ```
+pub fn transform_into(
+    s: &str,
+    transform: CaseTransform,
+    buf: &mut [u8],
+) -> Result<usize, BufferTooSmall> {
+    let capacity = buf.len();
+    let mut writer = TitleCaseWriter::with_transform(SliceWriter { buf, len: 0 }, transform);
+    let required = writer
+        .write_str(s)
+        .and_then(|()| writer.finish())
+        .map_or(usize::MAX, |out| out.len);
+    if required <= capacity {
+        Ok(required)
+    } else {
+        Err(BufferTooSmall { required })
+    }
+}
+
+/// Titlecases the first char of `s`, leaves the rest unchanged, and writes the result to the start
+/// of `buf` like [`crate::StrTitleCase::to_titlecase`]. Returns the number of bytes written.
+///
+/// # Errors
+/// Returns [`BufferTooSmall`] if the result does not fit in `buf`.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::to_titlecase_into;
+/// let mut buf = [0; 8];
+/// let len = to_titlecase_into("ﬄabc", &mut buf).unwrap();
+/// assert_eq!(&buf[..len], "Fflabc".as_bytes());
+/// ```
+pub fn to_titlecase_into(s: &str, buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+    transform_into(s, CaseTransform::Titlecase, buf)
+}
+
+/// Titlecases the first char of `s`, lowercases the rest, and writes the result to the start of
+/// `buf` like [`crate::StrTitleCase::to_titlecase_lower_rest`]. Returns the number of bytes
+/// written.
+///
+/// # Errors
+/// Returns [`BufferTooSmall`] if the result does not fit in `buf`.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::to_titlecase_lower_rest_into;
+/// let mut buf = [0; 16];
+/// let len = to_titlecase_lower_rest_into("ΟΔΟΣ", &mut buf).unwrap();
+/// assert_eq!(core::str::from_utf8(&buf[..len]), Ok("Οδος"));
+/// ```
+pub fn to_titlecase_lower_rest_into(s: &str, buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+    transform_into(s, CaseTransform::TitlecaseLowerRest, buf)
+}
+
+/// Titlecases the first char of `s` in the TR/AZ locale, leaves the rest unchanged, and writes the
+/// result to the start of `buf` like [`crate::StrTitleCase::to_titlecase_tr_or_az`]. Returns the
+/// number of bytes written.
+///
+/// # Errors
+/// Returns [`BufferTooSmall`] if the result does not fit in `buf`.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::to_titlecase_tr_or_az_into;
+/// let mut buf = [0; 16];
+/// let len = to_titlecase_tr_or_az_into("istanbul", &mut buf).unwrap();
+/// assert_eq!(core::str::from_utf8(&buf[..len]), Ok("İstanbul"));
+/// ```
+pub fn to_titlecase_tr_or_az_into(s: &str, buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+    transform_into(s, CaseTransform::TitlecaseTrOrAz, buf)
+}
+
+/// Titlecases the first char of `s` and lowercases the rest in the TR/AZ locale, and writes the
+/// result to the start of `buf` like [`crate::StrTitleCase::to_titlecase_tr_or_az_lower_rest`].
+/// Returns the number of bytes written.
+///
+/// # Errors
+/// Returns [`BufferTooSmall`] if the result does not fit in `buf`.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::to_titlecase_tr_or_az_lower_rest_into;
+/// let mut buf = [0; 16];
+/// let len = to_titlecase_tr_or_az_lower_rest_into("IŞIK", &mut buf).unwrap();
+/// assert_eq!(core::str::from_utf8(&buf[..len]), Ok("Işık"));
+/// ```
+pub fn to_titlecase_tr_or_az_lower_rest_into(
+    s: &str,
+    buf: &mut [u8],
+) -> Result<usize, BufferTooSmall> {
+    transform_into(s, CaseTransform::TitlecaseTrOrAzLowerRest, buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{transform_into, BufferTooSmall};
+    use crate::CaseTransform;
+
+    const CASES: &[(&str, CaseTransform, &str)] = &[
+        ("", CaseTransform::Titlecase, ""),
+        ("ﬄabc", CaseTransform::Titlecase, "Fflabc"),
+        ("abcdef", CaseTransform::Titlecase, "Abcdef"),
+        ("ﬄ ÿÿÿ", CaseTransform::TitlecaseLowerRest, "Ffl ÿÿÿ"),
+        ("ǆUNGLA", CaseTransform::TitlecaseLowerRest, "ǅungla"),
+        (
+            "ΟΔΟΣ ΣΤΑΣΗΣ",
+            CaseTransform::TitlecaseLowerRest,
+            "Οδος στασης",
+        ),
+        ("izmir", CaseTransform::TitlecaseTrOrAz, "İzmir"),
+        (
+            "KIRMIZI",
+            CaseTransform::TitlecaseTrOrAzLowerRest,
+            "Kırmızı",
+        ),
+        ("I\u{0307}ZMİR", CaseTransform::LowercaseTrAz, "izmir"),
+        ("istanbul", CaseTransform::UppercaseTrAz, "İSTANBUL"),
+    ];
+
+    #[test]
+    fn every_buffer_size() {
+        let mut buf = [0; 32];
+        for &(s, transform, expected) in CASES {
+            for size in 0..=expected.len() + 1 {
+                buf.fill(0);
+                let result = transform_into(s, transform, &mut buf[..size]);
+                if size < expected.len() {
+                    assert_eq!(
+                        result,
+                        Err(BufferTooSmall {
+                            required: expected.len()
+                        })
+                    );
+                    let mut fits = size;
+                    while !expected.is_char_boundary(fits) {
+                        fits -= 1;
+                    }
+                    assert_eq!(&buf[..fits], &expected.as_bytes()[..fits]);
+                    assert!(
+                        buf[fits..size].iter().all(|&b| b == 0),
+                        "For {s:?} in {size} bytes"
+                    );
+                } else {
+                    assert_eq!(result, Ok(expected.len()), "For {s:?} in {size} bytes");
+                    assert_eq!(&buf[..expected.len()], expected.as_bytes());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter, Result};
+use core::hash::{Hash, Hasher};
+
+use crate::fold::folder_in;
+use crate::{CaseMappingIter, Locale};
+
+/// A str that is compared, ordered and hashed by its Unicode full case folding, so it can be used
+/// as a case-insensitive `HashMap` or `BTreeMap` key. The original text is kept and returned by
+/// [`Caseless::as_str`] and [`Display`].
+///
+/// The Turkish and Azeri locales fold with the Turkic mappings: 'I' matches 'ı' and 'İ' matches
+/// 'i'. Values should be created with the same locale when they are compared.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::{Caseless, Locale};
+/// assert_eq!(Caseless::new("Straße"), Caseless::new("STRASSE"));
+/// assert_ne!(Caseless::new("KIRMIZI"), Caseless::new("kırmızı"));
+/// assert_eq!(
+///     Caseless::new_in("KIRMIZI", Locale::Turkish),
+///     Caseless::new_in("kırmızı", Locale::Turkish)
+/// );
+/// assert!(Caseless::new("apple") < Caseless::new("BANANA"));
+/// ```
+/// As a map key:
+/// ```
+/// use std::collections::HashMap;
+/// use unicode_titlecase::Caseless;
+/// let mut map = HashMap::new();
+/// map.insert(Caseless::new("Content-Type"), "text/plain");
+/// assert_eq!(map.get(&Caseless::new("content-type")), Some(&"text/plain"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Caseless<'a> {
+    text: Cow<'a, str>,
+    locale: Locale,
+}
+
+impl<'a> Caseless<'a> {
+    /// Wraps a str to compare it using the locale agnostic Unicode case folding.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::Caseless;
+    /// assert_eq!(Caseless::new("ǄEMAL"), Caseless::new("ǆemal"));
+    /// ```
+    #[must_use]
+    pub fn new(text: &'a str) -> Self {
+        Caseless::new_in(text, Locale::Root)
+    }
+
+    /// Wraps a str to compare it using the Unicode case folding of the given locale.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::{Caseless, Locale};
+    /// assert_eq!(Caseless::new_in("İZMİR", Locale::Azeri), Caseless::new_in("izmir", Locale::Azeri));
+    /// ```
+    #[must_use]
+    pub fn new_in(text: &'a str, locale: Locale) -> Self {
+        Caseless {
+            text: Cow::Borrowed(text),
+            locale,
+        }
+    }
+
+    /// Returns the wrapped str as it was given, without any case folding.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::Caseless;
+    /// assert_eq!(Caseless::new("Straße").as_str(), "Straße");
+    /// ```
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the locale whose case folding is used for comparisons.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::{Caseless, Locale};
+    /// assert_eq!(Caseless::new("abc").locale(), Locale::Root);
+    /// assert_eq!(Caseless::new_in("abc", Locale::Turkish).locale(), Locale::Turkish);
+    /// ```
+    #[must_use]
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// Copies the wrapped str into a [`CaselessString`] that owns it.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::Caseless;
+    /// let owned = Caseless::new("ABC").into_owned();
+    /// assert_eq!(owned.as_str(), "ABC");
+    /// ```
+    #[must_use]
+    pub fn into_owned(self) -> CaselessString {
+        CaselessString(Caseless {
+            text: Cow::Owned(self.text.into_owned()),
+            locale: self.locale,
+        })
+    }
+
+    /// The case folded chars of the wrapped str.
+    fn folded(&self) -> impl Iterator<Item = char> + '_ {
+        let fold = folder_in(self.locale);
+        self.text
+            .chars()
+            .flat_map(move |c| CaseMappingIter::new(fold(c)))
+    }
+}
+
+impl<'a> From<&'a str> for Caseless<'a> {
+    fn from(text: &'a str) -> Self {
+        Caseless::new(text)
+    }
+}
+
+impl<'b> PartialEq<Caseless<'b>> for Caseless<'_> {
+    fn eq(&self, other: &Caseless<'b>) -> bool {
+        self.folded().eq(other.folded())
+    }
+}
+
+impl Eq for Caseless<'_> {}
+
+impl<'b> PartialOrd<Caseless<'b>> for Caseless<'_> {
+    fn partial_cmp(&self, other: &Caseless<'b>) -> Option<Ordering> {
+        Some(self.folded().cmp(other.folded()))
+    }
+}
+
+impl Ord for Caseless<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.folded().cmp(other.folded())
+    }
+}
+
+impl Hash for Caseless<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for c in self.folded() {
+            state.write_u32(c.into());
+        }
+        // Not a valid char, so a string never hashes the same as one of its prefixes.
+        state.write_u32(u32::MAX);
+    }
+}
+
+impl Display for Caseless<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(&self.text, f)
+    }
+}
+
+/// An owned [`Caseless`]. It borrows as a [`Caseless`], so maps keyed by it can be searched with a
+/// [`Caseless`] wrapping a str without allocating.
+///
+/// # Examples
+/// ```
+/// use std::collections::BTreeMap;
+/// use unicode_titlecase::{Caseless, CaselessString, Locale};
+/// let mut map = BTreeMap::new();
+/// map.insert(CaselessString::new_in(String::from("Kırmızı"), Locale::Turkish), 1);
+/// assert_eq!(map.get(&Caseless::new_in("KIRMIZI", Locale::Turkish)), Some(&1));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CaselessString(Caseless<'static>);
+
+impl CaselessString {
+    /// Wraps a String to compare it using the locale agnostic Unicode case folding.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::CaselessString;
+    /// assert_eq!(CaselessString::new("ABC".into()), CaselessString::new("abc".into()));
+    /// ```
+    #[must_use]
+    pub fn new(text: String) -> Self {
+        CaselessString::new_in(text, Locale::Root)
+    }
+
+    /// Wraps a String to compare it using the Unicode case folding of the given locale.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::{CaselessString, Locale};
+    /// assert_eq!(
+    ///     CaselessString::new_in("KIRMIZI".into(), Locale::Turkish),
+    ///     CaselessString::new_in("kırmızı".into(), Locale::Turkish)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn new_in(text: String, locale: Locale) -> Self {
+        CaselessString(Caseless {
+            text: Cow::Owned(text),
+            locale,
+        })
+    }
+
+    /// Returns the wrapped str as it was given, without any case folding.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::CaselessString;
+    /// assert_eq!(CaselessString::new("Straße".into()).as_str(), "Straße");
+    /// ```
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Returns a [`Caseless`] borrowing this string.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::{Caseless, CaselessString};
+    /// let owned = CaselessString::new("ABC".into());
+    /// assert_eq!(owned.as_caseless(), &Caseless::new("abc"));
+    /// ```
+    #[must_use]
+    pub fn as_caseless(&self) -> &Caseless<'_> {
+        &self.0
+    }
+
+    /// Unwraps the String as it was given, without any case folding.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::CaselessString;
+    /// assert_eq!(CaselessString::new("ABC".into()).into_string(), "ABC");
+    /// ```
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.0.text.into_owned()
+    }
+}
+
+impl From<String> for CaselessString {
+    fn from(text: String) -> Self {
+        CaselessString::new(text)
+    }
+}
+
+impl<'a> Borrow<Caseless<'a>> for CaselessString {
+    fn borrow(&self) -> &Caseless<'a> {
+        &self.0
+    }
+}
+
+impl Display for CaselessString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(&self.0, f)
+    }
+}
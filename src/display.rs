@@ -0,0 +1,161 @@
+use core::fmt::{Alignment, Debug, Display, Formatter, Result, Write};
+
+use crate::{CaseTransform, TitleCaseWriter};
+
+/// Writes `s` with the transform applied to `out`.
+fn write_transformed<W: Write>(s: &str, transform: CaseTransform, out: W) -> Result {
+    let mut writer = TitleCaseWriter::with_transform(out, transform);
+    writer.write_str(s)?;
+    writer.finish().map(|_| ())
+}
+
+/// Counts the chars written to it.
+struct CharCount(usize);
+
+impl Write for CharCount {
+    fn write_str(&mut self, s: &str) -> Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Writes `s` with the transform applied straight into the formatter, padded to the formatter's
+/// width. The transformed text is produced twice when a width is set, once to measure it.
+fn display(s: &str, transform: CaseTransform, f: &mut Formatter<'_>) -> Result {
+    let Some(width) = f.width() else {
+        return write_transformed(s, transform, f);
+    };
+    let mut count = CharCount(0);
+    write_transformed(s, transform, &mut count)?;
+    let padding = width.saturating_sub(count.0);
+    let (before, after) = match f.align() {
+        Some(Alignment::Right) => (padding, 0),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (0, padding),
+    };
+    let fill = f.fill();
+    (0..before).try_for_each(|_| f.write_char(fill))?;
+    write_transformed(s, transform, &mut *f)?;
+    (0..after).try_for_each(|_| f.write_char(fill))
+}
+
+/// Escapes the chars written to it like [`str`]'s [`Debug`] impl does.
+struct EscapeDebug<'a, 'b>(&'a mut Formatter<'b>);
+
+impl Write for EscapeDebug<'_, '_> {
+    fn write_str(&mut self, s: &str) -> Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    fn write_char(&mut self, c: char) -> Result {
+        match c {
+            '\'' => self.0.write_char('\''),
+            _ => self.0.write_fmt(format_args!("{}", c.escape_debug())),
+        }
+    }
+}
+
+/// Writes `s` with the transform applied as a quoted and escaped string.
+fn debug(s: &str, transform: CaseTransform, f: &mut Formatter<'_>) -> Result {
+    f.write_char('"')?;
+    let mut writer = TitleCaseWriter::with_transform(EscapeDebug(f), transform);
+    writer.write_str(s)?;
+    writer.finish()?.0.write_char('"')
+}
+
+/// Displays a str with its first char titlecased and the rest unchanged, like
+/// [`crate::StrTitleCase::to_titlecase`], without allocating.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::TitleCased;
+/// assert_eq!(TitleCased("ǆungla").to_string(), "ǅungla");
+/// assert_eq!(format!("[{:>8}]", TitleCased("ﬄ")), "[     Ffl]");
+/// assert_eq!(format!("{:?}", TitleCased("ﬄ\"")), "\"Ffl\\\"\"");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TitleCased<'a>(pub &'a str);
+
+impl Display for TitleCased<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        display(self.0, CaseTransform::Titlecase, f)
+    }
+}
+
+impl Debug for TitleCased<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        debug(self.0, CaseTransform::Titlecase, f)
+    }
+}
+
+/// Displays a str with its first char titlecased and the rest lowercased, like
+/// [`crate::StrTitleCase::to_titlecase_lower_rest`], without allocating.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::TitleCasedLowerRest;
+/// assert_eq!(TitleCasedLowerRest("ΟΔΟΣ ΣΤΑΣΗΣ").to_string(), "Οδος στασης");
+/// assert_eq!(format!("{:?}", TitleCasedLowerRest("HELLO\n")), "\"Hello\\n\"");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TitleCasedLowerRest<'a>(pub &'a str);
+
+impl Display for TitleCasedLowerRest<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        display(self.0, CaseTransform::TitlecaseLowerRest, f)
+    }
+}
+
+impl Debug for TitleCasedLowerRest<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        debug(self.0, CaseTransform::TitlecaseLowerRest, f)
+    }
+}
+
+/// Displays a str with its first char titlecased in the TR/AZ locale and the rest unchanged, like
+/// [`crate::StrTitleCase::to_titlecase_tr_or_az`], without allocating.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::TitleCasedTrOrAz;
+/// assert_eq!(TitleCasedTrOrAz("istanbul").to_string(), "İstanbul");
+/// assert_eq!(format!("{:?}", TitleCasedTrOrAz("izmir")), "\"İzmir\"");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TitleCasedTrOrAz<'a>(pub &'a str);
+
+impl Display for TitleCasedTrOrAz<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        display(self.0, CaseTransform::TitlecaseTrOrAz, f)
+    }
+}
+
+impl Debug for TitleCasedTrOrAz<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        debug(self.0, CaseTransform::TitlecaseTrOrAz, f)
+    }
+}
+
+/// Displays a str with its first char titlecased and the rest lowercased in the TR/AZ locale, like
+/// [`crate::StrTitleCase::to_titlecase_tr_or_az_lower_rest`], without allocating.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::TitleCasedTrOrAzLowerRest;
+/// assert_eq!(TitleCasedTrOrAzLowerRest("IŞIK").to_string(), "Işık");
+/// assert_eq!(format!("{:?}", TitleCasedTrOrAzLowerRest("KIRMIZI")), "\"Kırmızı\"");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TitleCasedTrOrAzLowerRest<'a>(pub &'a str);
+
+impl Display for TitleCasedTrOrAzLowerRest<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        display(self.0, CaseTransform::TitlecaseTrOrAzLowerRest, f)
+    }
+}
+
+impl Debug for TitleCasedTrOrAzLowerRest<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        debug(self.0, CaseTransform::TitlecaseTrOrAzLowerRest, f)
+    }
+}
@@ -0,0 +1,325 @@
+//! A module to supply Greek (EL) locale specific upper and title case utilities.
+//!
+//! Greek drops the accents when a word is written in all caps, so "Καλημέρα" uppercases to
+//! "ΚΑΛΗΜΕΡΑ" rather than the Unicode default "ΚΑΛΗΜΈΡΑ". The uppercasing follows the CLDR Greek
+//! rules, which are also used by ICU:
+//!   1) The tonos and the other accents and breathing marks are removed, from the monotonic and the
+//!      polytonic letters alike. A ypogegrammeni becomes a capital 'Ι' after its letter, as in the
+//!      Unicode defaults.
+//!   2) The dialytika is kept. One is added to an 'ι' or 'υ' after a vowel that loses its accent,
+//!      since the two would otherwise read as a diphthong, so "Μάιος" uppercases to "ΜΑΪΟΣ".
+//!   3) The disjunctive eta, a standalone 'ή' meaning "or", keeps its tonos so that it is not read
+//!      as the article 'η'.
+//!
+//! A capital at the start of a mixed case word keeps its accent, as in "Άρης", so titlecasing and
+//! lowercasing follow the locale agnostic Unicode mappings. The rules need to see the chars around
+//! the one being mapped, so they are only applied by the string functions. Requires the `alloc`
+//! feature.
+//!
+//! ```
+//! use unicode_titlecase::el::StrElCasing;
+//! assert_eq!("Καλημέρα".to_uppercase_el(), "ΚΑΛΗΜΕΡΑ");
+//! assert_eq!("Μάιος".to_uppercase_el(), "ΜΑΪΟΣ");
+//! assert_eq!("ναι ή όχι".to_uppercase_el(), "ΝΑΙ Ή ΟΧΙ");
+//! assert_eq!("άρης".to_titlecase_el(), "Άρης");
+//! ```
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::iter::repeat_n;
+use core::ops::Range;
+
+use crate::{is_case_ignorable, is_cased, Locale, StrLocaleCasing, StrTitleCase};
+
+/// The letter is a vowel.
+const VOWEL: u8 = 1 << 0;
+/// The letter carries a tonos, oxia, varia or perispomeni.
+const ACCENT: u8 = 1 << 1;
+/// The letter is precomposed with a dialytika.
+const DIALYTIKA: u8 = 1 << 2;
+/// The letter is followed by a U+0308 COMBINING DIAERESIS.
+const COMBINING_DIALYTIKA: u8 = 1 << 3;
+/// The letter carries a ypogegrammeni, the iota subscript.
+const YPOGEGRAMMENI: u8 = 1 << 4;
+/// The letter carries a breathing mark, macron or breve, which are removed like the accents.
+const OTHER_DIACRITIC: u8 = 1 << 5;
+
+include!(concat!(env!("OUT_DIR"), "/greek.rs"));
+
+/// Returns the uppercase base letter and the flags of a Greek letter that is a vowel or carries a
+/// diacritic, or `None` for any other char.
+fn letter_data(c: char) -> Option<(char, u8)> {
+    GREEK_LETTER_TABLE
+        .binary_search_by(|&(key, _, _)| key.cmp(&c))
+        .ok()
+        .map(|index| (GREEK_LETTER_TABLE[index].1, GREEK_LETTER_TABLE[index].2))
+}
+
+/// Returns the flags of a combining diacritic that is removed or replaced when it follows a Greek
+/// letter, or 0 for any other char.
+fn diacritic_data(c: char) -> u8 {
+    match c {
+        '\u{0300}' | '\u{0301}' | '\u{0302}' | '\u{0303}' | '\u{0311}' | '\u{0342}' => ACCENT,
+        '\u{0308}' => COMBINING_DIALYTIKA,
+        '\u{0344}' => COMBINING_DIALYTIKA | ACCENT,
+        '\u{0345}' => YPOGEGRAMMENI,
+        '\u{0304}' | '\u{0306}' | '\u{0313}' | '\u{0314}' | '\u{0343}' => OTHER_DIACRITIC,
+        _ => 0,
+    }
+}
+
+/// Returns whether the end of `before` is a Greek vowel with an accent and no dialytika, skipping
+/// the combining diacritics after it. The result is true if the accent is precomposed and false if
+/// it is a combining mark, or `None` if there is no such vowel.
+fn after_accented_vowel(before: &str) -> Option<bool> {
+    let mut marks = 0;
+    for c in before.chars().rev() {
+        let diacritic = diacritic_data(c);
+        if diacritic == 0 {
+            let (_, flags) = letter_data(c)?;
+            let all = (flags | marks) & (VOWEL | ACCENT | DIALYTIKA | COMBINING_DIALYTIKA);
+            return (all == VOWEL | ACCENT).then_some(flags & ACCENT != 0);
+        }
+        marks |= diacritic;
+    }
+    None
+}
+
+/// Returns true if neither the text before `start` nor the text after `end` of `s` has a cased char
+/// next to it, skipping any case-ignorable chars in between. This is the word boundary of the
+/// `Final_Sigma` rule, and is used to find the disjunctive eta.
+fn is_standalone(s: &str, start: usize, end: usize) -> bool {
+    let mut before = s[..start]
+        .chars()
+        .rev()
+        .skip_while(|&c| is_case_ignorable(c));
+    let mut after = s[end..].chars().skip_while(|&c| is_case_ignorable(c));
+    !before.next().is_some_and(is_cased) && !after.next().is_some_and(is_cased)
+}
+
+/// Uppercases the chars of `s` in `range` with the Greek rules and appends them to `out`, using the
+/// whole of `s` as context.
+pub(crate) fn push_uppercase_el(out: &mut String, s: &str, range: Range<usize>) {
+    let mut i = range.start;
+    while let Some(c) = s[i..range.end].chars().next() {
+        let next = i + c.len_utf8();
+        let Some((mut upper, letter_flags)) = letter_data(c) else {
+            out.extend(c.to_uppercase());
+            i = next;
+            continue;
+        };
+        let marks_end = s[next..range.end]
+            .char_indices()
+            .find(|&(_, mark)| diacritic_data(mark) == 0)
+            .map_or(range.end, |(j, _)| next + j);
+        let mut flags = letter_flags;
+        let mut ypogegrammeni = usize::from(flags & YPOGEGRAMMENI != 0);
+        for mark in s[next..marks_end].chars() {
+            let diacritic = diacritic_data(mark);
+            flags |= diacritic;
+            ypogegrammeni += usize::from(diacritic & YPOGEGRAMMENI != 0);
+        }
+        if flags & VOWEL != 0 && matches!(upper, '\u{0399}' | '\u{03A5}') {
+            if let Some(precomposed) = after_accented_vowel(&s[..i]) {
+                flags |= if precomposed {
+                    DIALYTIKA
+                } else {
+                    COMBINING_DIALYTIKA
+                };
+            }
+        }
+        let mut add_tonos = false;
+        if upper == '\u{0397}'
+            && flags & ACCENT != 0
+            && ypogegrammeni == 0
+            && is_standalone(s, i, marks_end)
+        {
+            if letter_flags & ACCENT != 0 {
+                upper = '\u{0389}';
+            } else {
+                add_tonos = true;
+            }
+        } else if flags & DIALYTIKA != 0 {
+            // Keep the precomposed capital with a dialytika.
+            upper = match upper {
+                '\u{0399}' => '\u{03AA}',
+                '\u{03A5}' => '\u{03AB}',
+                other => other,
+            };
+            flags &= !(DIALYTIKA | COMBINING_DIALYTIKA);
+        }
+        out.push(upper);
+        if flags & (DIALYTIKA | COMBINING_DIALYTIKA) != 0 {
+            out.push('\u{0308}');
+        }
+        if add_tonos {
+            out.push('\u{0301}');
+        }
+        out.extend(repeat_n('\u{0399}', ypogegrammeni));
+        i = marks_end;
+    }
+}
+
+/// Returns true if [`push_uppercase_el`] would copy all of `s` unchanged. Any accent, breathing mark,
+/// ypogegrammeni or combining dialytika is taken as a change, so this returns false for some text
+/// that would not change, such as a standalone 'Ή'.
+pub(crate) fn uppercase_el_is_unchanged(s: &str) -> bool {
+    const CHANGED: u8 = ACCENT | COMBINING_DIALYTIKA | YPOGEGRAMMENI | OTHER_DIACRITIC;
+    s.chars().all(|c| {
+        let mut upper = c.to_uppercase();
+        let flags = letter_data(c).map_or(0, |(_, flags)| flags) | diacritic_data(c);
+        upper.len() == 1 && upper.next() == Some(c) && flags & CHANGED == 0
+    })
+}
+
+/// This trait provides functions to perform upper and title case transformations on a str in the
+/// EL locale. Requires the `alloc` feature.
+pub trait StrElCasing {
+    /// Returns the upper case of this str in the EL locale as a new String. Accents and breathing
+    /// marks are removed, and the dialytika is kept or added where it is needed.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::el::StrElCasing;
+    /// assert_eq!("Καλημέρα".to_uppercase_el(), "ΚΑΛΗΜΕΡΑ");
+    /// assert_eq!("ἀρχῇ".to_uppercase_el(), "ΑΡΧΗΙ");
+    /// assert_eq!("προϊόν".to_uppercase_el(), "ΠΡΟΪΟΝ");
+    /// assert_eq!("Μάιος".to_uppercase_el(), "ΜΑΪΟΣ");
+    /// ```
+    /// A standalone 'ή' keeps its tonos:
+    /// ```
+    /// use unicode_titlecase::el::StrElCasing;
+    /// assert_eq!("ή".to_uppercase_el(), "Ή");
+    /// assert_eq!("ναι ή όχι".to_uppercase_el(), "ΝΑΙ Ή ΟΧΙ");
+    /// assert_eq!("ήταν".to_uppercase_el(), "ΗΤΑΝ");
+    /// ```
+    fn to_uppercase_el(&self) -> String;
+
+    /// Titlecases the first char of a string in the EL locale, leaves the rest unchanged, and
+    /// returns a copy. The capital keeps its accent.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::el::StrElCasing;
+    /// assert_eq!("άρης".to_titlecase_el(), "Άρης");
+    /// assert_eq!("ᾳδης".to_titlecase_el(), "ᾼδης");
+    /// ```
+    fn to_titlecase_el(&self) -> String;
+
+    /// Titlecases the first char of a string in the EL locale, lowercases the rest of the string,
+    /// and returns a copy.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::el::StrElCasing;
+    /// assert_eq!("ΆΡΗΣ".to_titlecase_el_lower_rest(), "Άρης");
+    /// assert_eq!("ΟΔΟΣ".to_titlecase_el_lower_rest(), "Οδος");
+    /// ```
+    fn to_titlecase_el_lower_rest(&self) -> String;
+
+    /// Like [`StrElCasing::to_uppercase_el`], but borrows this str instead of allocating when it
+    /// would be returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::el::StrElCasing;
+    /// assert!(matches!("ΠΡΟΪΟΝ".to_uppercase_el_cow(), Cow::Borrowed(_)));
+    /// assert_eq!("ΚΑΛΗΜΈΡΑ".to_uppercase_el_cow(), "ΚΑΛΗΜΕΡΑ");
+    /// ```
+    fn to_uppercase_el_cow(&self) -> Cow<'_, str>;
+
+    /// Like [`StrElCasing::to_titlecase_el`], but borrows this str instead of allocating when it
+    /// would be returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::el::StrElCasing;
+    /// assert!(matches!("Άρης".to_titlecase_el_cow(), Cow::Borrowed(_)));
+    /// assert_eq!("άρης".to_titlecase_el_cow(), "Άρης");
+    /// ```
+    fn to_titlecase_el_cow(&self) -> Cow<'_, str>;
+
+    /// Like [`StrElCasing::to_titlecase_el_lower_rest`], but borrows this str instead of allocating
+    /// when it would be returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::el::StrElCasing;
+    /// assert!(matches!("Οδός".to_titlecase_el_lower_rest_cow(), Cow::Borrowed(_)));
+    /// assert_eq!("ΟΔΟΣ".to_titlecase_el_lower_rest_cow(), "Οδος");
+    /// ```
+    fn to_titlecase_el_lower_rest_cow(&self) -> Cow<'_, str>;
+}
+
+impl StrElCasing for str {
+    fn to_uppercase_el(&self) -> String {
+        self.to_uppercase_in(Locale::Greek)
+    }
+
+    fn to_titlecase_el(&self) -> String {
+        self.to_titlecase_in(Locale::Greek)
+    }
+
+    fn to_titlecase_el_lower_rest(&self) -> String {
+        self.to_titlecase_lower_rest_in(Locale::Greek)
+    }
+
+    fn to_uppercase_el_cow(&self) -> Cow<'_, str> {
+        self.to_uppercase_in_cow(Locale::Greek)
+    }
+
+    fn to_titlecase_el_cow(&self) -> Cow<'_, str> {
+        self.to_titlecase_in_cow(Locale::Greek)
+    }
+
+    fn to_titlecase_el_lower_rest_cow(&self) -> Cow<'_, str> {
+        self.to_titlecase_lower_rest_in_cow(Locale::Greek)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StrElCasing;
+
+    #[test]
+    fn uppercase() {
+        for (s, expected) in [
+            // Tonos, and the polytonic accents and breathings
+            ("ά έ ί ό ύ ώ", "Α Ε Ι Ο Υ Ω"),
+            ("Ά Έ Ί Ό Ύ Ώ", "Α Ε Ι Ο Υ Ω"),
+            ("ἄνθρωπος", "ΑΝΘΡΩΠΟΣ"),
+            ("ῥῆμα", "ΡΗΜΑ"),
+            ("α\u{0301}λ\u{0313}φα", "ΑΛ\u{0313}ΦΑ"),
+            // Ypogegrammeni
+            ("ᾳ ᾷ ᾼ", "ΑΙ ΑΙ ΑΙ"),
+            ("α\u{0345}\u{0342}", "ΑΙ"),
+            // Dialytika
+            ("ϊ ϋ ΐ ΰ", "Ϊ Ϋ Ϊ Ϋ"),
+            ("ι\u{0308}\u{0301}", "Ι\u{0308}"),
+            ("ο\u{0308}", "Ο\u{0308}"),
+            ("άι αι άυ", "ΑΪ ΑΙ ΑΫ"),
+            ("α\u{0301}ι", "ΑΙ\u{0308}"),
+            ("άϊ", "ΑΪ"),
+            ("ίι", "ΙΪ"),
+            ("άι\u{0301}", "ΑΪ"),
+            // Disjunctive eta
+            ("ή", "Ή"),
+            ("(ή)", "(Ή)"),
+            ("η\u{0301}", "Η\u{0301}"),
+            ("ή.", "Ή."),
+            ("αή", "ΑΗ"),
+            ("ήα", "ΗΑ"),
+            ("ῄ", "ΗΙ"),
+            ("η", "Η"),
+            // Other scripts and the Unicode defaults
+            ("Straße ǆ", "STRASSE Ǆ"),
+            ("aή bή", "AΗ BΗ"),
+            ("ά\u{0301}", "Α"),
+            ("\u{0301}ι", "\u{0301}Ι"),
+        ] {
+            assert_eq!(s.to_uppercase_el(), expected, "For {s:?}");
+        }
+    }
+}
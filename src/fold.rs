@@ -0,0 +1,238 @@
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[cfg(feature = "alloc")]
+use crate::ascii::push_mapped;
+#[cfg(feature = "alloc")]
+use crate::{borrow_if_unchanged, CaseMappingIter, Locale};
+
+include!(concat!(env!("OUT_DIR"), "/folding.rs"));
+
+/// Accepts a char and returns its Unicode full case folding as a 3 char array. This uses the C and F
+/// mappings from CaseFolding.txt and is what case-insensitive matching should compare.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::fold_case;
+/// assert_eq!(fold_case('A'), ['a', '\0', '\0']);
+/// assert_eq!(fold_case('a'), ['a', '\0', '\0']);
+/// assert_eq!(fold_case('ß'), ['s', 's', '\0']);
+/// assert_eq!(fold_case('ΐ'), ['ι', '\u{0308}', '\u{0301}']);
+/// ```
+/// Turkic dotted and dotless I are not folded together:
+/// ```
+/// use unicode_titlecase::fold_case;
+/// assert_eq!(fold_case('I'), ['i', '\0', '\0']);
+/// assert_eq!(fold_case('İ'), ['i', '\u{0307}', '\0']);
+/// assert_eq!(fold_case('ı'), ['ı', '\0', '\0']);
+/// ```
+/// # Locale
+/// This function is not locale specific. For the tr and az locales use
+/// [`crate::tr_az::fold_case_tr_or_az`].
+#[must_use]
+pub fn fold_case(c: char) -> [char; 3] {
+    if let Ok(index) = CASE_FOLDING_TABLE.binary_search_by(|&(key, _)| key.cmp(&c)) {
+        CASE_FOLDING_TABLE[index].1
+    } else {
+        [c, '\0', '\0']
+    }
+}
+
+/// Accepts a char and returns its Unicode simple case folding. This uses the C and S mappings from
+/// CaseFolding.txt, so every char folds to exactly one char. This is less accurate than
+/// [`fold_case`] but never changes the length of a string in chars.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::fold_case_simple;
+/// assert_eq!(fold_case_simple('A'), 'a');
+/// assert_eq!(fold_case_simple('ß'), 'ß');
+/// assert_eq!(fold_case_simple('ẞ'), 'ß');
+/// assert_eq!(fold_case_simple('İ'), 'İ');
+/// ```
+#[must_use]
+pub fn fold_case_simple(c: char) -> char {
+    SIMPLE_CASE_FOLDING_TABLE
+        .binary_search_by(|&(key, _)| key.cmp(&c))
+        .map_or(c, |index| SIMPLE_CASE_FOLDING_TABLE[index].1)
+}
+
+/// Accepts a char and returns its full case folding with the Turkic T mappings from
+/// CaseFolding.txt applied first.
+pub(crate) fn fold_case_turkic(c: char) -> [char; 3] {
+    TURKIC_CASE_FOLDING_TABLE
+        .binary_search_by(|&(key, _)| key.cmp(&c))
+        .map_or_else(
+            |_| fold_case(c),
+            |index| [TURKIC_CASE_FOLDING_TABLE[index].1, '\0', '\0'],
+        )
+}
+
+/// Returns the full case folding function of the locale.
+#[cfg(feature = "alloc")]
+pub(crate) fn folder_in(locale: Locale) -> fn(char) -> [char; 3] {
+    match locale {
+        Locale::Turkish | Locale::Azeri => fold_case_turkic,
+        _ => fold_case,
+    }
+}
+
+/// Trait to add Unicode case folding to strings and string slices. Two strings match
+/// case-insensitively when their case foldings are equal. Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+pub trait StrCaseFold {
+    /// Returns the Unicode full case folding of this str as a new String.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::StrCaseFold;
+    /// assert_eq!("Straße".to_case_folded(), "strasse");
+    /// assert_eq!("STRASSE".to_case_folded(), "strasse");
+    /// assert_eq!("ΣΊΣΥΦΟΣ".to_case_folded(), "σίσυφοσ");
+    /// ```
+    /// # Locale
+    /// This function is not locale specific. For the tr and az locales use
+    /// [`StrCaseFold::to_case_folded_in`].
+    fn to_case_folded(&self) -> String;
+
+    /// Returns the Unicode simple case folding of this str as a new String. The result always has
+    /// the same number of chars as this str.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::StrCaseFold;
+    /// assert_eq!("Straße".to_case_folded_simple(), "straße");
+    /// assert_eq!("STRAẞE".to_case_folded_simple(), "straße");
+    /// ```
+    fn to_case_folded_simple(&self) -> String;
+
+    /// Returns the Unicode full case folding of this str in the given locale as a new String. The
+    /// Turkish and Azeri locales use the Turkic T mappings, so 'I' folds to 'ı' and 'İ' folds to
+    /// 'i'. Other locales use the same folding as [`StrCaseFold::to_case_folded`].
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::{Locale, StrCaseFold};
+    /// assert_eq!("KIRMIZI".to_case_folded_in(Locale::Root), "kirmizi");
+    /// assert_eq!("KIRMIZI".to_case_folded_in(Locale::Turkish), "kırmızı");
+    /// assert_eq!("İZMİR".to_case_folded_in(Locale::Azeri), "izmir");
+    /// ```
+    fn to_case_folded_in(&self, locale: Locale) -> String;
+
+    /// Like [`StrCaseFold::to_case_folded`], but borrows this str instead of allocating when it is
+    /// already case folded.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::StrCaseFold;
+    /// assert!(matches!("strasse".to_case_folded_cow(), Cow::Borrowed(_)));
+    /// assert_eq!("Straße".to_case_folded_cow(), "strasse");
+    /// ```
+    fn to_case_folded_cow(&self) -> Cow<'_, str>;
+
+    /// Like [`StrCaseFold::to_case_folded_simple`], but borrows this str instead of allocating when
+    /// it is already case folded.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::StrCaseFold;
+    /// assert!(matches!("straße".to_case_folded_simple_cow(), Cow::Borrowed(_)));
+    /// assert_eq!("STRAẞE".to_case_folded_simple_cow(), "straße");
+    /// ```
+    fn to_case_folded_simple_cow(&self) -> Cow<'_, str>;
+
+    /// Like [`StrCaseFold::to_case_folded_in`], but borrows this str instead of allocating when it
+    /// is already case folded in the given locale.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::{Locale, StrCaseFold};
+    /// assert!(matches!("kırmızı".to_case_folded_in_cow(Locale::Turkish), Cow::Borrowed(_)));
+    /// assert_eq!("KIRMIZI".to_case_folded_in_cow(Locale::Turkish), "kırmızı");
+    /// ```
+    fn to_case_folded_in_cow(&self, locale: Locale) -> Cow<'_, str>;
+}
+
+#[cfg(feature = "alloc")]
+impl StrCaseFold for str {
+    fn to_case_folded(&self) -> String {
+        self.to_case_folded_in(Locale::Root)
+    }
+
+    fn to_case_folded_simple(&self) -> String {
+        let mut result = String::with_capacity(self.len());
+        push_mapped(
+            &mut result,
+            self,
+            0..self.len(),
+            b"",
+            str::make_ascii_lowercase,
+            |out, range| out.extend(self[range].chars().map(fold_case_simple)),
+        );
+        result
+    }
+
+    fn to_case_folded_in(&self, locale: Locale) -> String {
+        let fold = folder_in(locale);
+        let special: &[u8] = match locale {
+            Locale::Turkish | Locale::Azeri => b"I",
+            _ => b"",
+        };
+        let mut result = String::with_capacity(self.len());
+        push_mapped(
+            &mut result,
+            self,
+            0..self.len(),
+            special,
+            str::make_ascii_lowercase,
+            |out, range| {
+                out.extend(
+                    self[range]
+                        .chars()
+                        .flat_map(|c| CaseMappingIter::new(fold(c))),
+                );
+            },
+        );
+        result
+    }
+
+    fn to_case_folded_cow(&self) -> Cow<'_, str> {
+        self.to_case_folded_in_cow(Locale::Root)
+    }
+
+    fn to_case_folded_simple_cow(&self) -> Cow<'_, str> {
+        let unchanged = self.chars().all(|c| fold_case_simple(c) == c);
+        borrow_if_unchanged(self, unchanged, str::to_case_folded_simple)
+    }
+
+    fn to_case_folded_in_cow(&self, locale: Locale) -> Cow<'_, str> {
+        let fold = folder_in(locale);
+        let unchanged = self.chars().all(|c| fold(c) == [c, '\0', '\0']);
+        borrow_if_unchanged(self, unchanged, |s| s.to_case_folded_in(locale))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fold_case, CASE_FOLDING_TABLE, SIMPLE_CASE_FOLDING_TABLE};
+
+    #[test]
+    fn is_sorted() {
+        assert!(CASE_FOLDING_TABLE.is_sorted_by_key(|&(cp, _)| cp));
+        assert!(SIMPLE_CASE_FOLDING_TABLE.is_sorted_by_key(|&(cp, _)| cp));
+    }
+
+    #[test]
+    fn folding_is_stable() {
+        for c in (0..=u32::from(char::MAX)).filter_map(char::from_u32) {
+            for folded in fold_case(c).into_iter().filter(|&f| f != '\0') {
+                assert_eq!(fold_case(folded), [folded, '\0', '\0'], "For code point: {c}");
+            }
+        }
+    }
+}
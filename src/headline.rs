@@ -0,0 +1,375 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{is_cased, Locale};
+
+const CHICAGO_SMALL_WORDS: &[&str] = &[
+    "a", "about", "above", "across", "after", "against", "along", "among", "an", "and", "around",
+    "as", "at", "before", "behind", "below", "beneath", "beside", "between", "beyond", "but", "by",
+    "down", "during", "except", "for", "from", "in", "inside", "into", "like", "near", "nor", "of",
+    "off", "on", "onto", "or", "out", "outside", "over", "past", "per", "since", "than", "the",
+    "through", "throughout", "till", "to", "toward", "under", "underneath", "until", "up", "upon",
+    "via", "with", "within", "without",
+];
+
+const SHORT_SMALL_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "for", "if", "in", "nor", "of", "off", "on", "or",
+    "per", "so", "the", "to", "up", "via", "yet",
+];
+
+const MLA_SMALL_WORDS: &[&str] = &[
+    "a", "about", "above", "across", "after", "against", "along", "among", "an", "and", "around",
+    "as", "at", "before", "behind", "below", "beneath", "beside", "between", "beyond", "but", "by",
+    "down", "during", "except", "for", "from", "in", "inside", "into", "like", "near", "nor", "of",
+    "off", "on", "onto", "or", "out", "outside", "over", "past", "per", "since", "so", "than",
+    "the", "through", "throughout", "till", "to", "toward", "under", "underneath", "until", "up",
+    "upon", "via", "with", "within", "without", "yet",
+];
+
+const NEW_YORK_TIMES_SMALL_WORDS: &[&str] = &[
+    "a", "and", "as", "at", "but", "by", "en", "for", "if", "in", "of", "on", "or", "the", "to",
+    "v", "via", "vs",
+];
+
+/// Capitalizes headlines following a style guide. Every word is titlecased except the small words
+/// of the style, such as articles, coordinating conjunctions and short prepositions, which are
+/// lowercased. Small words are still capitalized when they are the first or last word of the
+/// headline, or the first or last word before or after a colon. Words are found using the Unicode
+/// word boundaries from [UAX #29](https://www.unicode.org/reports/tr29/).
+///
+/// The presets are associated constants. A custom style can be made with [`HeadlineCaser::new`].
+/// Requires the `alloc` feature.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::HeadlineCaser;
+/// assert_eq!(HeadlineCaser::CHICAGO.to_headline_case("the lord of the rings"), "The Lord of the Rings");
+/// assert_eq!(HeadlineCaser::CHICAGO.to_headline_case("star wars: a new hope"), "Star Wars: A New Hope");
+/// assert_eq!(HeadlineCaser::CHICAGO.to_headline_case("gone with the wind"), "Gone with the Wind");
+/// assert_eq!(HeadlineCaser::APA.to_headline_case("gone with the wind"), "Gone With the Wind");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HeadlineCaser<'a> {
+    small_words: &'a [&'a str],
+    min_capitalized_len: usize,
+    locale: Locale,
+}
+
+impl<'a> HeadlineCaser<'a> {
+    /// The Chicago Manual of Style. Lowercases articles, the coordinating conjunctions and, but,
+    /// for, or and nor, "as", "to" and prepositions of any length: a, about, above, across, after,
+    /// against, along, among, an, and, around, as, at, before, behind, below, beneath, beside,
+    /// between, beyond, but, by, down, during, except, for, from, in, inside, into, like, near,
+    /// nor, of, off, on, onto, or, out, outside, over, past, per, since, than, the, through,
+    /// throughout, till, to, toward, under, underneath, until, up, upon, via, with, within and
+    /// without. There is no length threshold, so "through" is lowercased as well.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::HeadlineCaser;
+    /// let caser = HeadlineCaser::CHICAGO;
+    /// assert_eq!(caser.to_headline_case("a walk through the woods"), "A Walk through the Woods");
+    /// assert_eq!(caser.to_headline_case("so you want to be a writer"), "So You Want to Be a Writer");
+    /// ```
+    pub const CHICAGO: HeadlineCaser<'static> = HeadlineCaser::new(CHICAGO_SMALL_WORDS, usize::MAX);
+
+    /// The APA Publication Manual. Lowercases articles, conjunctions and prepositions of three
+    /// letters or fewer: a, an, and, as, at, but, by, for, if, in, nor, of, off, on, or, per, so, the,
+    /// to, up, via and yet. Words of four letters or more are always capitalized.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::HeadlineCaser;
+    /// let caser = HeadlineCaser::APA;
+    /// assert_eq!(caser.to_headline_case("a walk through the woods"), "A Walk Through the Woods");
+    /// assert_eq!(caser.to_headline_case("what i learned from running"), "What I Learned From Running");
+    /// ```
+    pub const APA: HeadlineCaser<'static> = HeadlineCaser::new(SHORT_SMALL_WORDS, 4);
+
+    /// The MLA Handbook. Lowercases articles, prepositions of any length, the coordinating
+    /// conjunctions and, but, for, nor, or, so and yet, "as" and "to": a, about, above, across,
+    /// after, against, along, among, an, and, around, as, at, before, behind, below, beneath,
+    /// beside, between, beyond, but, by, down, during, except, for, from, in, inside, into, like,
+    /// near, nor, of, off, on, onto, or, out, outside, over, past, per, since, so, than, the,
+    /// through, throughout, till, to, toward, under, underneath, until, up, upon, via, with,
+    /// within, without and yet. There is no length threshold.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::HeadlineCaser;
+    /// let caser = HeadlineCaser::MLA;
+    /// assert_eq!(caser.to_headline_case("a walk through the woods"), "A Walk through the Woods");
+    /// assert_eq!(caser.to_headline_case("slow yet steady"), "Slow yet Steady");
+    /// ```
+    pub const MLA: HeadlineCaser<'static> = HeadlineCaser::new(MLA_SMALL_WORDS, usize::MAX);
+
+    /// The Associated Press Stylebook. Lowercases articles, conjunctions and prepositions of three
+    /// letters or fewer, using the same list as [`HeadlineCaser::APA`]. Words of four letters or more
+    /// are always capitalized.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::HeadlineCaser;
+    /// let caser = HeadlineCaser::AP;
+    /// assert_eq!(caser.to_headline_case("city council votes on new budget"), "City Council Votes on New Budget");
+    /// assert_eq!(caser.to_headline_case("stocks fall after rate hike"), "Stocks Fall After Rate Hike");
+    /// ```
+    pub const AP: HeadlineCaser<'static> = HeadlineCaser::new(SHORT_SMALL_WORDS, 4);
+
+    /// The New York Times Manual of Style and Usage. Lowercases only a, and, as, at, but, by, en, for,
+    /// if, in, of, on, or, the, to, v., via and vs. There is no length threshold, but every other
+    /// word is capitalized, including short prepositions such as "up" and "off".
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::HeadlineCaser;
+    /// let caser = HeadlineCaser::NEW_YORK_TIMES;
+    /// assert_eq!(caser.to_headline_case("prices go up as supply falls"), "Prices Go Up as Supply Falls");
+    /// assert_eq!(caser.to_headline_case("smith vs. the state"), "Smith vs. the State");
+    /// ```
+    pub const NEW_YORK_TIMES: HeadlineCaser<'static> =
+        HeadlineCaser::new(NEW_YORK_TIMES_SMALL_WORDS, usize::MAX);
+
+    /// Creates a custom style. `small_words` are matched ignoring ASCII case and are lowercased
+    /// unless they have at least `min_capitalized_len` chars. Pass [`usize::MAX`] to lowercase small
+    /// words of any length.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::HeadlineCaser;
+    /// let caser = HeadlineCaser::new(&["de", "la", "y"], usize::MAX);
+    /// assert_eq!(caser.to_headline_case("la casa de papel y la luna"), "La Casa de Papel y la Luna");
+    /// ```
+    #[must_use]
+    pub const fn new(small_words: &'a [&'a str], min_capitalized_len: usize) -> Self {
+        HeadlineCaser {
+            small_words,
+            min_capitalized_len,
+            locale: Locale::Root,
+        }
+    }
+
+    /// Returns a copy of this style that titlecases and lowercases words in the given locale.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::{HeadlineCaser, Locale};
+    /// let caser = HeadlineCaser::new(&["ve"], usize::MAX).with_locale(Locale::Turkish);
+    /// assert_eq!(caser.to_headline_case("istanbul ve izmir"), "İstanbul ve İzmir");
+    /// ```
+    #[must_use]
+    pub const fn with_locale(self, locale: Locale) -> Self {
+        HeadlineCaser { locale, ..self }
+    }
+
+    /// Capitalizes a headline, leaving the rest of every capitalized word unchanged so that acronyms
+    /// and names like "NASA" and "iPhone" are kept. Small words are lowercased.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::HeadlineCaser;
+    /// let caser = HeadlineCaser::CHICAGO;
+    /// assert_eq!(caser.to_headline_case("NASA and the race to the moon"), "NASA and the Race to the Moon");
+    /// assert_eq!(caser.to_headline_case("THE END OF AN ERA"), "THE END of an ERA");
+    /// ```
+    #[must_use]
+    pub fn to_headline_case(&self, s: &str) -> String {
+        self.headline_case(s, false)
+    }
+
+    /// Capitalizes a headline and lowercases the rest of every word.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::HeadlineCaser;
+    /// let caser = HeadlineCaser::CHICAGO;
+    /// assert_eq!(caser.to_headline_case_lower_rest("THE END OF AN ERA"), "The End of an Era");
+    /// assert_eq!(caser.to_headline_case_lower_rest("tHE lORD oF tHE rINGS"), "The Lord of the Rings");
+    /// ```
+    #[must_use]
+    pub fn to_headline_case_lower_rest(&self, s: &str) -> String {
+        self.headline_case(s, true)
+    }
+
+    fn is_small_word(&self, word: &str) -> bool {
+        word.chars().count() < self.min_capitalized_len
+            && self
+                .small_words
+                .iter()
+                .any(|small| small.eq_ignore_ascii_case(word))
+    }
+
+    fn headline_case(&self, s: &str, lower_rest: bool) -> String {
+        let segments: Vec<(usize, &str)> = self.locale.split_words(s).collect();
+        let is_word = |segment: &str| segment.chars().any(is_cased);
+
+        // The first and last words of the headline and of each part around a colon are always
+        // capitalized.
+        let mut always_capitalize = alloc::vec![false; segments.len()];
+        let mut at_boundary = true;
+        for (i, &(start, segment)) in segments.iter().enumerate() {
+            if ends_part(s, start, segment) {
+                at_boundary = true;
+            } else if at_boundary && is_word(segment) {
+                always_capitalize[i] = true;
+                at_boundary = false;
+            }
+        }
+        at_boundary = true;
+        for (i, &(start, segment)) in segments.iter().enumerate().rev() {
+            if ends_part(s, start, segment) {
+                at_boundary = true;
+            } else if at_boundary && is_word(segment) {
+                always_capitalize[i] = true;
+                at_boundary = false;
+            }
+        }
+
+        let mut result = String::with_capacity(s.len());
+        for (&(start, segment), always) in segments.iter().zip(always_capitalize) {
+            let end = start + segment.len();
+            let first_cased = segment.char_indices().find(|&(_, c)| is_cased(c));
+            match first_cased {
+                Some(_) if !always && self.is_small_word(segment) => {
+                    self.locale.push_lowercase(&mut result, s, start..end);
+                }
+                Some((i, _)) => {
+                    result.push_str(&segment[..i]);
+                    let rest = start
+                        + i
+                        + self
+                            .locale
+                            .push_titlecase_first(&mut result, &s[start + i..end]);
+                    if lower_rest {
+                        self.locale.push_lowercase(&mut result, s, rest..end);
+                    } else {
+                        result.push_str(&s[rest..end]);
+                    }
+                }
+                None => result.push_str(segment),
+            }
+        }
+        result
+    }
+}
+
+/// Returns whether the segment of `s` at `start` has a colon that splits the headline, one followed
+/// by whitespace or the end of `s`. The colons in "10:30" or a URL are not followed by either.
+fn ends_part(s: &str, start: usize, segment: &str) -> bool {
+    segment.match_indices(':').any(|(i, _)| {
+        s[start + i + 1..]
+            .chars()
+            .next()
+            .is_none_or(char::is_whitespace)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HeadlineCaser;
+
+    const FIXTURES: &[(&str, [&str; 5])] = &[
+        // Input, then Chicago, APA, MLA, AP and New York Times.
+        (
+            "the lord of the rings",
+            [
+                "The Lord of the Rings",
+                "The Lord of the Rings",
+                "The Lord of the Rings",
+                "The Lord of the Rings",
+                "The Lord of the Rings",
+            ],
+        ),
+        (
+            "a walk through the woods",
+            [
+                "A Walk through the Woods",
+                "A Walk Through the Woods",
+                "A Walk through the Woods",
+                "A Walk Through the Woods",
+                "A Walk Through the Woods",
+            ],
+        ),
+        (
+            "prices go up so demand falls",
+            [
+                "Prices Go up So Demand Falls",
+                "Prices Go up so Demand Falls",
+                "Prices Go up so Demand Falls",
+                "Prices Go up so Demand Falls",
+                "Prices Go Up So Demand Falls",
+            ],
+        ),
+        (
+            "what the sun is about",
+            [
+                "What the Sun Is About",
+                "What the Sun Is About",
+                "What the Sun Is About",
+                "What the Sun Is About",
+                "What the Sun Is About",
+            ],
+        ),
+        (
+            "war and peace: the story of a nation at war",
+            [
+                "War and Peace: The Story of a Nation at War",
+                "War and Peace: The Story of a Nation at War",
+                "War and Peace: The Story of a Nation at War",
+                "War and Peace: The Story of a Nation at War",
+                "War and Peace: The Story of a Nation at War",
+            ],
+        ),
+        (
+            "the road to: an odyssey",
+            [
+                "The Road To: An Odyssey",
+                "The Road To: An Odyssey",
+                "The Road To: An Odyssey",
+                "The Road To: An Odyssey",
+                "The Road To: An Odyssey",
+            ],
+        ),
+        (
+            "life without limits, if only",
+            [
+                "Life without Limits, If Only",
+                "Life Without Limits, if Only",
+                "Life without Limits, If Only",
+                "Life Without Limits, if Only",
+                "Life Without Limits, if Only",
+            ],
+        ),
+        (
+            "meet at 10:30 in the hall",
+            [
+                "Meet at 10:30 in the Hall",
+                "Meet at 10:30 in the Hall",
+                "Meet at 10:30 in the Hall",
+                "Meet at 10:30 in the Hall",
+                "Meet at 10:30 in the Hall",
+            ],
+        ),
+    ];
+
+    #[test]
+    fn style_fixtures() {
+        let styles = [
+            HeadlineCaser::CHICAGO,
+            HeadlineCaser::APA,
+            HeadlineCaser::MLA,
+            HeadlineCaser::AP,
+            HeadlineCaser::NEW_YORK_TIMES,
+        ];
+        for (input, expected) in FIXTURES {
+            for (style, expected) in styles.iter().zip(expected) {
+                assert_eq!(&style.to_headline_case(input), expected, "{style:?}");
+                assert_eq!(
+                    &style.to_headline_case_lower_rest(&input.to_uppercase()),
+                    expected,
+                    "{style:?}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,553 @@
+//! Streaming casing for [`std::io`] readers and writers. Requires the `std` feature.
+//!
+//! [`CaseWriter`] and [`CaseReader`] decode UTF-8 as it arrives, so text of any size can be
+//! transformed without loading it into a `String`. A multi-byte sequence may be split between
+//! buffers. Invalid UTF-8 fails with an [`io::ErrorKind::InvalidData`] error that wraps an
+//! [`InvalidUtf8`] holding the byte offset of the invalid sequence.
+//!
+//! ```
+//! use std::io::{Read, Write};
+//! use unicode_titlecase::io::{CaseReader, CaseWriter};
+//! use unicode_titlecase::CaseTransform;
+//!
+//! let mut writer = CaseWriter::new(Vec::new(), CaseTransform::TitlecaseLowerRest);
+//! writer.write_all(b"HELLO \xCE").unwrap();
+//! writer.write_all(b"\xA3\xCE\xA3 WORLD").unwrap();
+//! assert_eq!(writer.finish().unwrap(), "Hello σς world".as_bytes());
+//!
+//! let mut reader = CaseReader::new("IŞIK".as_bytes(), CaseTransform::LowercaseTrAz);
+//! let mut text = String::new();
+//! reader.read_to_string(&mut text).unwrap();
+//! assert_eq!(text, "ışık");
+//! ```
+use core::fmt::{self, Display, Formatter};
+use core::str;
+use std::io::{self, BufRead, Read, Write};
+use std::string::String;
+use std::vec::Vec;
+
+use crate::{CaseTransform, TitleCaseWriter};
+
+/// The error wrapped by the [`io::Error`] returned for invalid UTF-8.
+///
+/// # Examples
+/// ```
+/// use std::io::Write;
+/// use unicode_titlecase::io::{CaseWriter, InvalidUtf8};
+/// use unicode_titlecase::CaseTransform;
+///
+/// let mut writer = CaseWriter::new(Vec::new(), CaseTransform::Titlecase);
+/// let error = writer.write_all(b"abc\xFF").unwrap_err();
+/// let invalid = error.get_ref().and_then(|e| e.downcast_ref::<InvalidUtf8>()).unwrap();
+/// assert_eq!(invalid.offset(), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvalidUtf8 {
+    offset: u64,
+}
+
+impl InvalidUtf8 {
+    /// Returns the offset of the first byte of the invalid sequence, counted from the start of the
+    /// stream.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::io::Read;
+    /// use unicode_titlecase::io::{CaseReader, InvalidUtf8};
+    /// use unicode_titlecase::CaseTransform;
+    ///
+    /// let mut reader = CaseReader::new(&b"ab\xC3("[..], CaseTransform::Titlecase);
+    /// let error = reader.read_to_end(&mut Vec::new()).unwrap_err();
+    /// let invalid = error.get_ref().and_then(|e| e.downcast_ref::<InvalidUtf8>()).unwrap();
+    /// assert_eq!(invalid.offset(), 2);
+    /// ```
+    #[must_use]
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+}
+
+impl Display for InvalidUtf8 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid UTF-8 at byte offset {}", self.offset)
+    }
+}
+
+impl std::error::Error for InvalidUtf8 {}
+
+/// Returns the length of the UTF-8 sequence that starts with `first`.
+fn sequence_len(first: u8) -> usize {
+    match first {
+        0xF0.. => 4,
+        0xE0.. => 3,
+        0xC0.. => 2,
+        _ => 1,
+    }
+}
+
+/// Decodes UTF-8 that may be split at any byte, keeping an incomplete sequence at the end of one
+/// buffer until the next one completes it.
+#[derive(Debug, Clone, Default)]
+struct Utf8Decoder {
+    partial: [u8; 4],
+    partial_len: usize,
+    offset: u64,
+}
+
+impl Utf8Decoder {
+    fn invalid(&self) -> io::Error {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            InvalidUtf8 {
+                offset: self.offset,
+            },
+        )
+    }
+
+    /// Decodes `bytes` and passes each complete part to `f`.
+    fn decode(
+        &mut self,
+        mut bytes: &[u8],
+        mut f: impl FnMut(&str) -> io::Result<()>,
+    ) -> io::Result<()> {
+        if self.partial_len > 0 {
+            let len = sequence_len(self.partial[0]);
+            let taken = bytes.len().min(len - self.partial_len);
+            self.partial[self.partial_len..self.partial_len + taken]
+                .copy_from_slice(&bytes[..taken]);
+            self.partial_len += taken;
+            bytes = &bytes[taken..];
+            match str::from_utf8(&self.partial[..self.partial_len]) {
+                Ok(s) => {
+                    f(s)?;
+                    self.offset += len as u64;
+                    self.partial_len = 0;
+                }
+                Err(e) if e.error_len().is_some() => return Err(self.invalid()),
+                Err(_) => return Ok(()),
+            }
+        }
+        let (valid, rest) = match str::from_utf8(bytes) {
+            Ok(s) => (s, &[][..]),
+            Err(e) => {
+                let (valid, rest) = bytes.split_at(e.valid_up_to());
+                // The prefix was just validated, so this never falls back to the default.
+                let valid = str::from_utf8(valid).unwrap_or_default();
+                if e.error_len().is_some() {
+                    f(valid)?;
+                    self.offset += valid.len() as u64;
+                    return Err(self.invalid());
+                }
+                (valid, rest)
+            }
+        };
+        f(valid)?;
+        self.offset += valid.len() as u64;
+        self.partial[..rest.len()].copy_from_slice(rest);
+        self.partial_len = rest.len();
+        Ok(())
+    }
+
+    /// Returns the offset of the next byte, counting the bytes of an incomplete sequence.
+    fn position(&self) -> u64 {
+        self.offset + self.partial_len as u64
+    }
+
+    /// Fails if the stream ended inside a multi-byte sequence.
+    fn finish(&self) -> io::Result<()> {
+        if self.partial_len > 0 {
+            Err(self.invalid())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Forwards formatted text to an [`io::Write`], keeping the error that [`fmt::Write`] cannot return.
+#[derive(Debug)]
+struct IoAdapter<W: Write> {
+    inner: W,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> fmt::Write for IoAdapter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            fmt::Error
+        })
+    }
+}
+
+impl<W: Write> IoAdapter<W> {
+    /// Converts a [`fmt::Error`] from the casing writer back into the [`io::Error`] that caused it.
+    fn take_error(&mut self) -> io::Error {
+        self.error
+            .take()
+            .unwrap_or_else(|| io::Error::other("formatter error"))
+    }
+}
+
+/// An [`io::Write`] adapter that decodes the bytes written to it as UTF-8, applies a
+/// [`CaseTransform`], and writes the result to the wrapped writer.
+///
+/// Some chars are held back until the chars after them are known, and a multi-byte sequence may be
+/// incomplete at the end of a write. Call [`CaseWriter::finish`] after the last write to pass them
+/// on and check that the stream ended on a complete char.
+///
+/// A write that contains invalid UTF-8 accepts the valid bytes before it, and the next call returns
+/// the error.
+///
+/// # Examples
+/// ```
+/// use std::io::Write;
+/// use unicode_titlecase::io::CaseWriter;
+/// use unicode_titlecase::CaseTransform;
+///
+/// let mut writer = CaseWriter::new(Vec::new(), CaseTransform::UppercaseTrAz);
+/// writer.write_all("istanbul".as_bytes()).unwrap();
+/// assert_eq!(writer.finish().unwrap(), "İSTANBUL".as_bytes());
+/// ```
+#[derive(Debug)]
+pub struct CaseWriter<W: Write> {
+    casing: TitleCaseWriter<IoAdapter<W>>,
+    decoder: Utf8Decoder,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> CaseWriter<W> {
+    /// Wraps a writer to apply the given transform to the text written.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::io::Write;
+    /// use unicode_titlecase::io::CaseWriter;
+    /// use unicode_titlecase::CaseTransform;
+    ///
+    /// let mut writer = CaseWriter::new(Vec::new(), CaseTransform::Titlecase);
+    /// writer.write_all(b"abc").unwrap();
+    /// assert_eq!(writer.finish().unwrap(), b"Abc");
+    /// ```
+    pub fn new(inner: W, transform: CaseTransform) -> Self {
+        CaseWriter {
+            casing: TitleCaseWriter::with_transform(IoAdapter { inner, error: None }, transform),
+            decoder: Utf8Decoder::default(),
+            error: None,
+        }
+    }
+
+    /// Returns a reference to the wrapped writer.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::io::Write;
+    /// use unicode_titlecase::io::CaseWriter;
+    /// use unicode_titlecase::CaseTransform;
+    ///
+    /// let mut writer = CaseWriter::new(Vec::new(), CaseTransform::Titlecase);
+    /// writer.write_all(b"abc").unwrap();
+    /// assert_eq!(writer.get_ref(), b"Abc");
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        &self.casing.get_ref().inner
+    }
+
+    /// Writes any chars that are held back and returns the wrapped writer.
+    ///
+    /// # Errors
+    /// Returns an error if the stream ended inside a multi-byte UTF-8 sequence or the wrapped writer
+    /// fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::io::Write;
+    /// use unicode_titlecase::io::CaseWriter;
+    /// use unicode_titlecase::CaseTransform;
+    ///
+    /// let mut writer = CaseWriter::new(Vec::new(), CaseTransform::TitlecaseLowerRest);
+    /// writer.write_all("ΑΣ".as_bytes()).unwrap();
+    /// assert_eq!(writer.finish().unwrap(), "Ας".as_bytes());
+    ///
+    /// let mut writer = CaseWriter::new(Vec::new(), CaseTransform::Titlecase);
+    /// writer.write_all(b"ab\xC3").unwrap();
+    /// assert!(writer.finish().is_err());
+    /// ```
+    pub fn finish(mut self) -> io::Result<W> {
+        if let Some(error) = self.error.take() {
+            return Err(error);
+        }
+        self.decoder.finish()?;
+        if self.casing.flush_pending().is_err() {
+            return Err(self.casing.get_mut().take_error());
+        }
+        Ok(self.casing.into_inner().inner)
+    }
+}
+
+impl<W: Write> Write for CaseWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Some(error) = self.error.take() {
+            return Err(error);
+        }
+        let start = self.decoder.position();
+        let casing = &mut self.casing;
+        let result = self.decoder.decode(buf, |s| {
+            fmt::Write::write_str(casing, s).map_err(|_| casing.get_mut().take_error())
+        });
+        match result {
+            Ok(()) => Ok(buf.len()),
+            Err(error) => {
+                // The decoder only counts the bytes it passed on, so this is at most `buf.len()`.
+                let written =
+                    usize::try_from(self.decoder.offset.saturating_sub(start)).unwrap_or_default();
+                if written == 0 {
+                    return Err(error);
+                }
+                self.error = Some(error);
+                Ok(written)
+            }
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.casing.get_mut().inner.flush()
+    }
+}
+
+/// A [`BufRead`] adapter that decodes the bytes read from the wrapped reader as UTF-8, applies a
+/// [`CaseTransform`], and returns the result.
+///
+/// The text before invalid UTF-8 is returned first, and the read after it returns the error.
+///
+/// # Examples
+/// ```
+/// use std::io::BufRead;
+/// use unicode_titlecase::io::CaseReader;
+/// use unicode_titlecase::CaseTransform;
+///
+/// let reader = CaseReader::new("KIRMIZI\nIŞIK\n".as_bytes(), CaseTransform::LowercaseTrAz);
+/// let lines: Vec<String> = reader.lines().map(Result::unwrap).collect();
+/// assert_eq!(lines, ["kırmızı", "ışık"]);
+/// ```
+#[derive(Debug)]
+pub struct CaseReader<R: BufRead> {
+    inner: R,
+    casing: TitleCaseWriter<String>,
+    decoder: Utf8Decoder,
+    buf: Vec<u8>,
+    pos: usize,
+    done: bool,
+    error: Option<io::Error>,
+}
+
+impl<R: BufRead> CaseReader<R> {
+    /// Wraps a reader to apply the given transform to the text read from it.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::io::Read;
+    /// use unicode_titlecase::io::CaseReader;
+    /// use unicode_titlecase::CaseTransform;
+    ///
+    /// let mut reader = CaseReader::new("HELLO".as_bytes(), CaseTransform::TitlecaseLowerRest);
+    /// let mut text = String::new();
+    /// reader.read_to_string(&mut text).unwrap();
+    /// assert_eq!(text, "Hello");
+    /// ```
+    pub fn new(inner: R, transform: CaseTransform) -> Self {
+        CaseReader {
+            inner,
+            casing: TitleCaseWriter::with_transform(String::new(), transform),
+            decoder: Utf8Decoder::default(),
+            buf: Vec::new(),
+            pos: 0,
+            done: false,
+            error: None,
+        }
+    }
+
+    /// Returns a reference to the wrapped reader.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::io::CaseReader;
+    /// use unicode_titlecase::CaseTransform;
+    ///
+    /// let reader = CaseReader::new(&b"abc"[..], CaseTransform::Titlecase);
+    /// assert_eq!(reader.get_ref(), b"abc");
+    /// ```
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns the wrapped reader. Text that was read from it but not yet returned is lost.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::io::CaseReader;
+    /// use unicode_titlecase::CaseTransform;
+    ///
+    /// let reader = CaseReader::new(&b"abc"[..], CaseTransform::Titlecase);
+    /// assert_eq!(reader.into_inner(), b"abc");
+    /// ```
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads from the wrapped reader until some transformed text is available or it ends.
+    fn refill(&mut self) -> io::Result<()> {
+        while self.pos == self.buf.len() && !self.done {
+            if let Some(error) = self.error.take() {
+                return Err(error);
+            }
+            let input = self.inner.fill_buf()?;
+            let at_end = input.is_empty();
+            let casing = &mut self.casing;
+            let result = if at_end {
+                casing
+                    .flush_pending()
+                    .map_err(|_| io::Error::other("formatter error"))?;
+                self.decoder.finish()
+            } else {
+                let len = input.len();
+                let result = self.decoder.decode(input, |s| {
+                    fmt::Write::write_str(casing, s)
+                        .map_err(|_| io::Error::other("formatter error"))
+                });
+                self.inner.consume(len);
+                result
+            };
+            // The text cased before an error is returned before the error itself.
+            self.buf = core::mem::take(self.casing.get_mut()).into_bytes();
+            self.pos = 0;
+            match result {
+                Ok(()) => self.done = at_end,
+                Err(error) => self.error = Some(error),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<R: BufRead> Read for CaseReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.consume(len);
+        Ok(len)
+    }
+}
+
+impl<R: BufRead> BufRead for CaseReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.refill()?;
+        Ok(&self.buf[self.pos..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = (self.pos + amt).min(self.buf.len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CaseReader, CaseWriter, InvalidUtf8};
+    use crate::CaseTransform;
+    use std::io::{self, BufReader, Read, Write};
+    use std::string::String;
+    use std::vec::Vec;
+
+    const TEXT: &str = "ΟΔΟΣ ΣΤΑΣΗΣ, KIRMIZI I\u{0307}ZMIR ﬄ 字";
+
+    fn offset(error: &io::Error) -> u64 {
+        error
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<InvalidUtf8>())
+            .unwrap()
+            .offset()
+    }
+
+    #[test]
+    fn writer_splits_at_every_byte() {
+        let expected = crate::StrTitleCase::to_titlecase_tr_or_az_lower_rest(TEXT);
+        for split in 0..=TEXT.len() {
+            let mut writer = CaseWriter::new(Vec::new(), CaseTransform::TitlecaseTrOrAzLowerRest);
+            writer.write_all(&TEXT.as_bytes()[..split]).unwrap();
+            writer.write_all(&TEXT.as_bytes()[split..]).unwrap();
+            assert_eq!(
+                writer.finish().unwrap(),
+                expected.as_bytes(),
+                "split at {split}"
+            );
+        }
+    }
+
+    #[test]
+    fn reader_with_tiny_buffers() {
+        let expected = crate::StrTitleCase::to_titlecase_lower_rest(TEXT);
+        for capacity in 1..=5 {
+            let inner = BufReader::with_capacity(capacity, TEXT.as_bytes());
+            let mut reader = CaseReader::new(inner, CaseTransform::TitlecaseLowerRest);
+            let mut text = String::new();
+            reader.read_to_string(&mut text).unwrap();
+            assert_eq!(text, expected, "capacity {capacity}");
+        }
+    }
+
+    #[test]
+    fn invalid_utf8_offsets() {
+        let cases: [(&[u8], u64); 5] = [
+            (b"\xFF", 0),
+            (b"abc\x80", 3),
+            (b"a\xCE\xA3\xE0\x41", 3),
+            ("字".as_bytes().split_at(2).0, 0),
+            (b"ok\xF0\x9F\x98", 2),
+        ];
+        for (bytes, expected) in cases {
+            let mut writer = CaseWriter::new(Vec::new(), CaseTransform::Titlecase);
+            let error = bytes
+                .iter()
+                .try_for_each(|b| writer.write_all(&[*b]))
+                .err()
+                .or_else(|| writer.finish().err())
+                .unwrap();
+            assert_eq!(offset(&error), expected, "{bytes:?}");
+
+            let inner = BufReader::with_capacity(2, bytes);
+            let mut reader = CaseReader::new(inner, CaseTransform::Titlecase);
+            let error = reader.read_to_end(&mut Vec::new()).unwrap_err();
+            assert_eq!(offset(&error), expected, "{bytes:?}");
+        }
+    }
+
+    #[test]
+    fn writer_accepts_valid_prefix() {
+        let mut writer = CaseWriter::new(Vec::new(), CaseTransform::Titlecase);
+        assert_eq!(writer.write(b"abc\xFFdef").unwrap(), 3);
+        assert_eq!(writer.get_ref(), b"Abc");
+        assert_eq!(offset(&writer.write(b"def").unwrap_err()), 3);
+
+        let mut writer = CaseWriter::new(Vec::new(), CaseTransform::Titlecase);
+        assert_eq!(writer.write(b"\xCE").unwrap(), 1);
+        assert_eq!(writer.write(b"\xA3\xFF").unwrap(), 1);
+        assert_eq!(writer.get_ref(), "Σ".as_bytes());
+        assert_eq!(offset(&writer.finish().unwrap_err()), 2);
+    }
+
+    #[test]
+    fn reader_returns_text_before_error() {
+        for bytes in [&b"ab\xC3("[..], &b"ab\xC3"[..]] {
+            let mut reader = CaseReader::new(bytes, CaseTransform::Titlecase);
+            let mut buf = [0; 8];
+            assert_eq!(reader.read(&mut buf).unwrap(), 2, "{bytes:?}");
+            assert_eq!(&buf[..2], b"Ab");
+            assert_eq!(offset(&reader.read(&mut buf).unwrap_err()), 2, "{bytes:?}");
+        }
+
+        // A stream that ends inside a sequence keeps failing instead of reporting a clean end.
+        let mut reader = CaseReader::new(&b"\xCE"[..], CaseTransform::Titlecase);
+        assert!(reader.read(&mut [0; 8]).is_err());
+        assert!(reader.read(&mut [0; 8]).is_err());
+    }
+}
@@ -1,24 +1,92 @@
-#![doc = include_str!("../README.md")]
+// Most of the README examples use the String functions, so without `alloc` the crate docs are
+// limited to what still works.
+#![cfg_attr(feature = "alloc", doc = include_str!("../README.md"))]
+#![cfg_attr(
+    not(feature = "alloc"),
+    doc = r#"Unicode titlecasing operations for chars and strings, built without the `alloc` feature.
+
+The char functions, the iterators, `TitleCaseWriter` and the `TitleCased` wrappers work without a
+heap, and the `_into` functions write to a byte slice instead of returning a `String`.
+
+```rust
+use unicode_titlecase::{to_titlecase, to_titlecase_lower_rest_into, to_titlecase_into};
+assert_eq!(to_titlecase('ﬄ'), ['F', 'f', 'l']);
+let mut buf = [0; 16];
+let len = to_titlecase_lower_rest_into("HELLO WORLD", &mut buf).unwrap();
+assert_eq!(&buf[..len], b"Hello world");
+assert_eq!(to_titlecase_into("ﬄabc", &mut buf[..4]).unwrap_err().required(), 6);
+```"#
+)]
 #![no_std]
 #![deny(missing_docs)]
 #![deny(rustdoc::missing_doc_code_examples)]
 #![deny(unsafe_code)]
 #![warn(clippy::pedantic)]
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
 use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::fmt::{Debug, Display, Formatter, Result, Write};
+#[cfg(feature = "alloc")]
+use core::iter::once;
 use core::iter::FusedIterator;
+#[cfg(feature = "alloc")]
+use core::ops::Range;
+
+#[cfg(feature = "alloc")]
+use unicode_segmentation::UnicodeSegmentation;
+
+#[cfg(feature = "alloc")]
+use crate::ascii::ascii_prefix_len;
 
 // This function was originally in the main module but was moved
 // to tr_az in 2.2.0. This re-export exists to avoid a major change.
 // It should be removed and a part of the next major version.
 pub use tr_az::to_titlecase_tr_or_az;
 
-use crate::tr_az::to_lowercase_tr_or_az;
+pub use buffer::{
+    to_titlecase_into, to_titlecase_lower_rest_into, to_titlecase_tr_or_az_into,
+    to_titlecase_tr_or_az_lower_rest_into, transform_into, BufferTooSmall,
+};
+#[cfg(feature = "alloc")]
+pub use caseless::{Caseless, CaselessString};
+pub use display::{TitleCased, TitleCasedLowerRest, TitleCasedTrOrAz, TitleCasedTrOrAzLowerRest};
+#[cfg(feature = "alloc")]
+pub use fold::StrCaseFold;
+pub use fold::{fold_case, fold_case_simple};
+#[cfg(feature = "alloc")]
+pub use headline::HeadlineCaser;
+#[cfg(feature = "alloc")]
+pub use locale::StrLocaleCasing;
+pub use locale::{to_titlecase_in, Locale};
+pub use writer::{CaseTransform, TitleCaseWriter};
+
+mod ascii;
+mod buffer;
+#[cfg(feature = "alloc")]
+mod caseless;
+mod display;
+#[cfg(feature = "alloc")]
+pub mod el;
+mod fold;
+#[cfg(feature = "alloc")]
+mod headline;
+#[cfg(feature = "std")]
+pub mod io;
+mod locale;
+pub mod lt;
+mod writer;
 
 include!(concat!(env!("OUT_DIR"), "/casing.rs"));
+include!(concat!(env!("OUT_DIR"), "/properties.rs"));
 
 #[allow(clippy::doc_link_with_quotes)]
 /// Accepts a char and returns the Unicode title case for that character as a 3 char array.
@@ -49,10 +117,366 @@ include!(concat!(env!("OUT_DIR"), "/casing.rs"));
 /// this function does not take into account. For tr and az locales use [`to_titlecase_tr_or_az`]
 #[must_use]
 pub fn to_titlecase(c: char) -> [char; 3] {
-    if let Ok(index) = TITLECASE_TABLE.binary_search_by(|&(key, _)| key.cmp(&c)) {
-        TITLECASE_TABLE[index].1
+    let index = titlecase_mapping_index(c);
+    match TITLECASE_OFFSETS.get(index) {
+        Some(&offset) => {
+            let mapped = char::from_u32(u32::from(c).wrapping_add_signed(offset));
+            [mapped.unwrap_or(c), '\0', '\0']
+        }
+        None => TITLECASE_EXPANSIONS[index - TITLECASE_OFFSETS.len()],
+    }
+}
+
+/// Looks up the char in the three-stage titlecase trie generated by build.rs. Returns the index of
+/// its titlecase mapping in `TITLECASE_OFFSETS` followed by `TITLECASE_EXPANSIONS`, which is 0 for
+/// every char that titlecases to itself.
+fn titlecase_mapping_index(c: char) -> usize {
+    let cp = c as usize;
+    let Some(&top) = TITLECASE_TOP.get(cp >> (TITLECASE_LEAF_BITS + TITLECASE_MIDDLE_BITS)) else {
+        return 0;
+    };
+    let middle_index = (cp >> TITLECASE_LEAF_BITS) & ((1 << TITLECASE_MIDDLE_BITS) - 1);
+    let leaf = TITLECASE_MIDDLE[usize::from(top) << TITLECASE_MIDDLE_BITS | middle_index];
+    let leaf_index = cp & ((1 << TITLECASE_LEAF_BITS) - 1);
+    usize::from(TITLECASE_LEAVES[usize::from(leaf) << TITLECASE_LEAF_BITS | leaf_index])
+}
+
+/// Returns true if the char has the Unicode `Cased` property: it is lowercase, uppercase, or a
+/// titlecase letter (general category Lt). Digits, punctuation, spaces and letters of scripts without
+/// case such as CJK are not cased.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::is_cased;
+/// assert!(is_cased('a'));
+/// assert!(is_cased('A'));
+/// assert!(is_cased('ǅ'));
+///
+/// assert!(!is_cased('1'));
+/// assert!(!is_cased(' '));
+/// assert!(!is_cased('字'));
+/// ```
+#[must_use]
+pub fn is_cased(c: char) -> bool {
+    CASED_TABLE.binary_search(&c).is_ok()
+}
+
+/// Returns true if the char has the Unicode `Case_Ignorable` property. These chars, such as
+/// apostrophes, periods and combining marks, are skipped when looking for the cased chars around a
+/// position, as in the `Final_Sigma` rule.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::is_case_ignorable;
+/// assert!(is_case_ignorable('\''));
+/// assert!(is_case_ignorable('.'));
+/// assert!(is_case_ignorable('\u{0301}'));
+///
+/// assert!(!is_case_ignorable('a'));
+/// assert!(!is_case_ignorable(' '));
+/// ```
+#[must_use]
+pub fn is_case_ignorable(c: char) -> bool {
+    CASE_IGNORABLE_TABLE.binary_search(&c).is_ok()
+}
+
+/// Returns true if the char has the Unicode `Changes_When_Titlecased` property: titlecasing it with
+/// [`to_titlecase`] gives a different result.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::changes_when_titlecased;
+/// assert!(changes_when_titlecased('a'));
+/// assert!(changes_when_titlecased('Ǆ'));
+/// assert!(changes_when_titlecased('ﬄ'));
+///
+/// assert!(!changes_when_titlecased('A'));
+/// assert!(!changes_when_titlecased('ǅ'));
+/// assert!(!changes_when_titlecased('1'));
+/// ```
+#[must_use]
+pub fn changes_when_titlecased(c: char) -> bool {
+    titlecase_mapping_index(c) != 0
+}
+
+/// Whether a char is titlecase, as returned by [`titlecase_class`].
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::{titlecase_class, TitlecaseClass};
+/// assert_eq!(titlecase_class('A'), TitlecaseClass::Titlecase);
+/// assert_eq!(titlecase_class('a'), TitlecaseClass::NotTitlecase);
+/// assert_eq!(titlecase_class('1'), TitlecaseClass::Uncased);
+/// ```
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum TitlecaseClass {
+    /// The char is cased and titlecasing does not change it, like 'A' or 'ǅ'.
+    Titlecase,
+    /// The char is cased and titlecasing changes it, like 'a', 'Ǆ' or 'ﬄ'.
+    NotTitlecase,
+    /// The char has no case and titlecasing does not change it, like '1', ' ' or '字'.
+    Uncased,
+}
+
+/// Classifies a char as titlecase, not titlecase, or uncased. Unlike [`TitleCase::is_titlecase`],
+/// this tells apart a cased char that is already titlecase from a char that has no case at all.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::{titlecase_class, TitlecaseClass};
+/// assert_eq!(titlecase_class('ǅ'), TitlecaseClass::Titlecase);
+/// assert_eq!(titlecase_class('İ'), TitlecaseClass::Titlecase);
+/// assert_eq!(titlecase_class('Ǆ'), TitlecaseClass::NotTitlecase);
+/// assert_eq!(titlecase_class('ﬄ'), TitlecaseClass::NotTitlecase);
+/// assert_eq!(titlecase_class('字'), TitlecaseClass::Uncased);
+/// assert_eq!(titlecase_class('-'), TitlecaseClass::Uncased);
+/// ```
+#[must_use]
+pub fn titlecase_class(c: char) -> TitlecaseClass {
+    if changes_when_titlecased(c) {
+        TitlecaseClass::NotTitlecase
+    } else if is_cased(c) {
+        TitlecaseClass::Titlecase
     } else {
-        [c, '\0', '\0']
+        TitlecaseClass::Uncased
+    }
+}
+
+/// Returns the canonical combining class of the char. Most chars, including all base letters, are
+/// class 0.
+pub(crate) fn combining_class(c: char) -> u8 {
+    COMBINING_CLASS_TABLE
+        .binary_search_by(|&(key, _)| key.cmp(&c))
+        .map_or(0, |index| COMBINING_CLASS_TABLE[index].1)
+}
+
+/// Returns true if the U+03A3 GREEK CAPITAL LETTER SIGMA starting at byte `i` of `s` meets the
+/// Unicode `Final_Sigma` condition: it is preceded by a cased char and not followed by one, skipping
+/// any case-ignorable chars in between.
+#[cfg(feature = "alloc")]
+pub(crate) fn is_final_sigma(s: &str, i: usize) -> bool {
+    let mut before = s[..i].chars().rev().skip_while(|&c| is_case_ignorable(c));
+    let mut after = s[i + '\u{03A3}'.len_utf8()..]
+        .chars()
+        .skip_while(|&c| is_case_ignorable(c));
+    before.next().is_some_and(is_cased) && !after.next().is_some_and(is_cased)
+}
+
+/// Lowercases the chars of `s` in `range` with `lower` and appends them to `out`. The whole of `s`
+/// is used as context for the `Final_Sigma` rule, so a capital sigma at the end of a word becomes 'ς'.
+#[cfg(feature = "alloc")]
+pub(crate) fn push_lowercase<I: Iterator<Item = char>>(
+    out: &mut String,
+    s: &str,
+    range: Range<usize>,
+    lower: impl Fn(char) -> I,
+) {
+    let start = range.start;
+    for (i, c) in s[range].char_indices() {
+        if c == '\u{03A3}' && is_final_sigma(s, start + i) {
+            out.push('\u{03C2}');
+        } else {
+            out.extend(lower(c));
+        }
+    }
+}
+
+/// Titlecases the first cased char of every word of `s` in the locale. The rest of each word is
+/// lowercased if `lower_rest` is true and left unchanged otherwise.
+#[cfg(feature = "alloc")]
+fn titlecase_words(s: &str, locale: Locale, lower_rest: bool) -> String {
+    let mut result = String::with_capacity(s.len());
+    for (start, word) in locale.split_words(s) {
+        let end = start + word.len();
+        if let Some((i, _)) = word.char_indices().find(|&(_, c)| is_cased(c)) {
+            result.push_str(&word[..i]);
+            let rest = start + i + locale.push_titlecase_first(&mut result, &s[start + i..end]);
+            if lower_rest {
+                locale.push_lowercase(&mut result, s, rest..end);
+            } else {
+                result.push_str(&s[rest..end]);
+            }
+        } else {
+            result.push_str(word);
+        }
+    }
+    result
+}
+
+/// Returns the byte index of the start of every sentence of `s`. These are the Unicode sentence
+/// boundaries from UAX #29, plus the whitespace after every full stop. UAX #29 does not end a
+/// sentence at a full stop followed by a lowercase word because it may be an abbreviation, but text
+/// that is being sentence cased is often all lowercase.
+#[cfg(feature = "alloc")]
+fn sentence_starts(s: &str) -> Vec<usize> {
+    let mut starts: Vec<usize> = s.split_sentence_bound_indices().map(|(i, _)| i).collect();
+    let mut chars = s.char_indices().peekable();
+    while let Some((_, c)) = chars.next() {
+        if c == '.' {
+            while chars
+                .next_if(|&(_, c)| matches!(c, '"' | '\'' | ')' | ']' | '}' | '»' | '’' | '”'))
+                .is_some()
+            {}
+            if let Some(&(i, c)) = chars.peek() {
+                if c.is_whitespace() {
+                    starts.push(i);
+                }
+            }
+        }
+    }
+    starts.sort_unstable();
+    starts.dedup();
+    starts
+}
+
+/// Titlecases the first cased char of every sentence of `s` in the locale and lowercases the rest of
+/// each sentence in the same locale.
+#[cfg(feature = "alloc")]
+fn sentence_case(s: &str, locale: Locale) -> String {
+    let mut result = String::with_capacity(s.len());
+    let starts = sentence_starts(s);
+    let ends = starts.iter().skip(1).copied().chain(once(s.len()));
+    for (start, end) in starts.iter().copied().zip(ends) {
+        let sentence = &s[start..end];
+        if let Some((i, _)) = sentence.char_indices().find(|&(_, c)| is_cased(c)) {
+            result.push_str(&sentence[..i]);
+            let rest = start + i + locale.push_titlecase_first(&mut result, &s[start + i..end]);
+            locale.push_lowercase(&mut result, s, rest..end);
+        } else {
+            result.push_str(sentence);
+        }
+    }
+    result
+}
+
+/// Returns true if lowercasing the char in the locale returns it unchanged.
+fn lowercases_to_self(c: char, locale: Locale) -> bool {
+    match locale {
+        Locale::Turkish | Locale::Azeri => tr_az::to_lowercase_tr_or_az(c) == c,
+        _ => {
+            let mut lower = c.to_lowercase();
+            lower.len() == 1 && lower.next() == Some(c)
+        }
+    }
+}
+
+/// Returns true if uppercasing the char in the locale returns it unchanged.
+fn uppercases_to_self(c: char, locale: Locale) -> bool {
+    match locale {
+        Locale::Turkish | Locale::Azeri => {
+            let mut upper = tr_az::to_uppercase_tr_or_az(c);
+            upper.len() == 1 && upper.next() == Some(c)
+        }
+        _ => {
+            let mut upper = c.to_uppercase();
+            upper.len() == 1 && upper.next() == Some(c)
+        }
+    }
+}
+
+/// Returns true if titlecasing the start of `s` in the locale returns it unchanged and, when
+/// `lower_rest` is true, lowercasing the rest does too. The lowercasing rules that look at the chars
+/// around a char only apply to chars that change anyway, so the rest is checked one char at a time.
+fn titlecase_is_unchanged(s: &str, locale: Locale, lower_rest: bool) -> bool {
+    locale
+        .titlecase_first_unchanged(s)
+        .is_some_and(|rest| !lower_rest || s[rest..].chars().all(|c| lowercases_to_self(c, locale)))
+}
+
+/// Returns true if [`titlecase_words`] would return `s` unchanged.
+fn titlecase_words_is_unchanged(s: &str, locale: Locale, lower_rest: bool) -> bool {
+    locale.split_words(s).all(|(_, word)| {
+        word.char_indices()
+            .find(|&(_, c)| is_cased(c))
+            .is_none_or(|(i, _)| titlecase_is_unchanged(&word[i..], locale, lower_rest))
+    })
+}
+
+/// Returns true if [`sentence_case`] would return `s` unchanged.
+#[cfg(feature = "alloc")]
+fn sentence_case_is_unchanged(s: &str, locale: Locale) -> bool {
+    let starts = sentence_starts(s);
+    let ends = starts.iter().skip(1).copied().chain(once(s.len()));
+    starts.iter().copied().zip(ends).all(|(start, end)| {
+        let sentence = &s[start..end];
+        sentence
+            .char_indices()
+            .find(|&(_, c)| is_cased(c))
+            .is_none_or(|(i, _)| titlecase_is_unchanged(&sentence[i..], locale, true))
+    })
+}
+
+/// Borrows `s` if `unchanged` is true, and otherwise calls `map` to make an owned copy.
+#[cfg(feature = "alloc")]
+fn borrow_if_unchanged(s: &str, unchanged: bool, map: impl FnOnce(&str) -> String) -> Cow<'_, str> {
+    if unchanged {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(map(s))
+    }
+}
+
+/// Replaces the char that starts at byte `i` of `s` and is `len` bytes long with the `mapped` chars.
+/// When the first mapped char has the same UTF-8 length, its bytes are overwritten without moving the
+/// rest of the string, so only mappings to several chars need a splice. Returns the byte length of
+/// the replacement.
+#[cfg(feature = "alloc")]
+pub(crate) fn replace_char(
+    s: &mut String,
+    i: usize,
+    len: usize,
+    mapped: impl Iterator<Item = char>,
+) -> usize {
+    let mut end = i;
+    let mut buf = [0; 4];
+    for (n, c) in mapped.enumerate() {
+        let encoded = c.encode_utf8(&mut buf);
+        if n > 0 {
+            s.insert_str(end, encoded);
+        } else if s[i..i + len] != *encoded {
+            s.replace_range(i..i + len, encoded);
+        }
+        end += encoded.len();
+    }
+    if end == i {
+        s.replace_range(i..i + len, "");
+    }
+    end - i
+}
+
+/// Returns the byte length of the first char of `s`, or 0 if it is empty.
+#[cfg(feature = "alloc")]
+fn first_char_len(s: &str) -> usize {
+    s.chars().next().map_or(0, char::len_utf8)
+}
+
+/// Titlecases the first char of `s` in place in the locale.
+#[cfg(feature = "alloc")]
+fn make_titlecase_first(s: &mut String, locale: Locale) {
+    if let Some(first) = s.chars().next() {
+        replace_char(s, 0, first.len_utf8(), first.to_titlecase_in(locale));
+    }
+}
+
+/// Lowercases `s` in place from byte `start` to the end, applying the `Final_Sigma` rule.
+/// Lowercasing keeps cased chars cased and case-ignorable chars case-ignorable, so the already
+/// lowercased chars before each char are still valid context. Titlecasing does not, so the first
+/// char of a string must be titlecased after the rest is lowercased.
+#[cfg(feature = "alloc")]
+fn make_lowercase_from(s: &mut String, start: usize) {
+    let mut i = start;
+    loop {
+        let ascii_end = i + ascii_prefix_len(&s[i..], b"");
+        s[i..ascii_end].make_ascii_lowercase();
+        i = ascii_end;
+        let Some(c) = s[i..].chars().next() else {
+            break;
+        };
+        i += if c == '\u{03A3}' && is_final_sigma(s, i) {
+            replace_char(s, i, c.len_utf8(), once('\u{03C2}'))
+        } else {
+            replace_char(s, i, c.len_utf8(), c.to_lowercase())
+        };
     }
 }
 
@@ -116,6 +540,17 @@ pub trait TitleCase {
     /// chars. To use locale agnostic version see [`TitleCase::to_titlecase`].
     fn to_titlecase_tr_or_az(self) -> ToTitleCase;
 
+    /// Wraps [`to_titlecase_in`] in an iterator. The iterator will yield at most 3 chars.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::{Locale, TitleCase};
+    /// assert_eq!('i'.to_titlecase_in(Locale::Root).to_string(), "I");
+    /// assert_eq!('i'.to_titlecase_in(Locale::Turkish).to_string(), "İ");
+    /// assert_eq!('ﬄ'.to_titlecase_in(Locale::Azeri).to_string(), "Ffl");
+    /// ```
+    fn to_titlecase_in(self, locale: Locale) -> ToTitleCase;
+
     /// Returns true if the given character is a titlecase character. This function works for all locales
     /// including tr and az.
     /// # Examples
@@ -129,7 +564,27 @@ pub trait TitleCase {
     /// assert!(!'Ǆ'.is_titlecase());
     /// assert!(!'ﬄ'.is_titlecase());
     /// ```
+    /// # Uncased chars
+    /// This returns true for every char that titlecasing does not change, including chars with no
+    /// case such as digits, punctuation and spaces. Use [`TitleCase::titlecase_class`] to tell
+    /// those apart from cased titlecase chars.
+    /// ```
+    /// use unicode_titlecase::TitleCase;
+    /// assert!('1'.is_titlecase());
+    /// assert!(' '.is_titlecase());
+    /// ```
     fn is_titlecase(&self) -> bool;
+
+    /// Classifies this char as titlecase, not titlecase, or uncased. See [`titlecase_class`].
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::{TitleCase, TitlecaseClass};
+    /// assert_eq!('A'.titlecase_class(), TitlecaseClass::Titlecase);
+    /// assert_eq!('a'.titlecase_class(), TitlecaseClass::NotTitlecase);
+    /// assert_eq!('1'.titlecase_class(), TitlecaseClass::Uncased);
+    /// ```
+    fn titlecase_class(&self) -> TitlecaseClass;
 }
 
 impl TitleCase for char {
@@ -138,19 +593,26 @@ impl TitleCase for char {
     }
 
     fn to_titlecase_tr_or_az(self) -> ToTitleCase {
-        ToTitleCase(CaseMappingIter::new(to_titlecase_tr_or_az(self)))
+        self.to_titlecase_in(Locale::Turkish)
+    }
+
+    fn to_titlecase_in(self, locale: Locale) -> ToTitleCase {
+        ToTitleCase(CaseMappingIter::new(to_titlecase_in(self, locale)))
     }
 
     fn is_titlecase(&self) -> bool {
-        TITLECASE_TABLE
-            .binary_search_by(|&(key, _)| key.cmp(self))
-            .is_err()
+        !changes_when_titlecased(*self)
+    }
+
+    fn titlecase_class(&self) -> TitlecaseClass {
+        titlecase_class(*self)
     }
 }
 
 
 /// Trait to add titlecase operations to Strings and string slices. Both locale agnostic and TR/AZ
-/// versions of the functions are supplied.
+/// versions of the functions are supplied. The methods that return a String or a `Cow` require the
+/// `alloc` feature.
 pub trait StrTitleCase {
     /// Titlecases the first char of a string, leaves the rest unchanged, and returns a copy.
     ///
@@ -178,6 +640,7 @@ pub trait StrTitleCase {
     /// # Locale
     /// This function is not locale specific. Unicode special casing has rules for tr and az that
     /// this function does not take into account. For tr and az locales use [`StrTitleCase::to_titlecase_tr_or_az`]
+    #[cfg(feature = "alloc")]
     fn to_titlecase(&self) -> String;
     /// Titlecases the first char of a string, lowercases the rest of the string, and returns a copy.
     ///
@@ -202,9 +665,16 @@ pub trait StrTitleCase {
     /// use unicode_titlecase::StrTitleCase;
     /// assert_eq!("iIi".to_titlecase_lower_rest(), "Iii")
     /// ```
+    /// A capital sigma at the end of a word lowercases to the final form 'ς':
+    /// ```
+    /// use unicode_titlecase::StrTitleCase;
+    /// assert_eq!("ΟΔΟΣ".to_titlecase_lower_rest(), "Οδος");
+    /// assert_eq!("ΟΔΟΣ ΣΤΑΣΗΣ".to_titlecase_lower_rest(), "Οδος στασης");
+    /// ```
     /// # Locale
     /// This function is not locale specific. Unicode special casing has rules for tr and az that
     /// this function does not take into account. For tr and az locales use [`StrTitleCase::to_titlecase_tr_or_az_lower_rest`]
+    #[cfg(feature = "alloc")]
     fn to_titlecase_lower_rest(&self) -> String;
     /// This functions the same way as [`StrTitleCase::to_titlecase`] except that it uses the TR/AZ
     /// locales. This has one major change:
@@ -214,6 +684,7 @@ pub trait StrTitleCase {
     /// ```
     ///
     /// For the locale agnostic version use [`StrTitleCase::to_titlecase`].
+    #[cfg(feature = "alloc")]
     fn to_titlecase_tr_or_az(&self) -> String;
     /// This functions the same way as [`StrTitleCase::to_titlecase_lower_rest`] except that it uses
     /// the TR/AZ locales. This has one major change, 'i' maps to 'İ':
@@ -227,8 +698,164 @@ pub trait StrTitleCase {
     ///   2) 'İ' maps to 'i'--\u{130} LATIN CAPITAL LETTER I WITH DOT ABOVE -> \u{0069} LATIN SMALL LETTER I
     ///
     /// For the locale agnostic version use [`StrTitleCase::to_titlecase_lower_rest`].
+    #[cfg(feature = "alloc")]
     fn to_titlecase_tr_or_az_lower_rest(&self) -> String;
 
+    /// Titlecases the first cased char of every word, leaves the rest unchanged, and returns a copy.
+    /// Words are found using the Unicode word boundaries from
+    /// [UAX #29](https://www.unicode.org/reports/tr29/), so punctuation and non-Latin scripts are
+    /// split correctly. Chars before the first cased char of a word, such as digits or an opening
+    /// quote, are left as they are.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::StrTitleCase;
+    /// assert_eq!("the quick brown fox".to_titlecase_words(), "The Quick Brown Fox");
+    /// assert_eq!("hello-world, \"quoted\"".to_titlecase_words(), "Hello-World, \"Quoted\"");
+    /// assert_eq!("o'neil's ﬄoor".to_titlecase_words(), "O'neil's Ffloor");
+    /// assert_eq!("привет мир".to_titlecase_words(), "Привет Мир");
+    /// ```
+    /// The rest of each word is left unchanged:
+    /// ```
+    /// use unicode_titlecase::StrTitleCase;
+    /// assert_eq!("NASA's ǆungla".to_titlecase_words(), "NASA's ǅungla");
+    /// ```
+    /// # Locale
+    /// This function is not locale specific. Unicode special casing has rules for tr and az that
+    /// this function does not take into account.
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_words(&self) -> String;
+
+    /// Titlecases the first cased char of every word, lowercases the rest of each word, and returns
+    /// a copy. This is the Unicode `toTitlecase(X)` operation. Words are found using the Unicode word
+    /// boundaries from [UAX #29](https://www.unicode.org/reports/tr29/).
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::StrTitleCase;
+    /// assert_eq!("THE QUICK BROWN FOX".to_titlecase_words_lower_rest(), "The Quick Brown Fox");
+    /// assert_eq!("NASA's ǄUNGLA".to_titlecase_words_lower_rest(), "Nasa's ǅungla");
+    /// assert_eq!("ПРИВЕТ МИР".to_titlecase_words_lower_rest(), "Привет Мир");
+    /// assert_eq!("ΟΔΟΣ ΣΤΑΣΗΣ".to_titlecase_words_lower_rest(), "Οδος Στασης");
+    /// assert_eq!("ﬄOOR PLAN".to_titlecase_words_lower_rest(), "Ffloor Plan");
+    /// ```
+    /// # Locale
+    /// This function is not locale specific. Unicode special casing has rules for tr and az that
+    /// this function does not take into account.
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_words_lower_rest(&self) -> String;
+
+    /// Titlecases the first char of a string in the given locale, leaves the rest unchanged, and
+    /// returns a copy.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::{Locale, StrTitleCase};
+    /// assert_eq!("iIi".to_titlecase_in(Locale::Root), "IIi");
+    /// assert_eq!("iIi".to_titlecase_in(Locale::Turkish), "İIi");
+    /// assert_eq!("i\u{0307}\u{0300}s".to_titlecase_in(Locale::Lithuanian), "I\u{0300}s");
+    /// assert_eq!("ijsselmeer".to_titlecase_in(Locale::Dutch), "IJsselmeer");
+    /// assert_eq!("ngaeilge".to_titlecase_in(Locale::Irish), "nGaeilge");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_in(&self, locale: Locale) -> String;
+
+    /// Titlecases the first char of a string in the given locale, lowercases the rest of the string
+    /// in the same locale, and returns a copy.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::{Locale, StrTitleCase};
+    /// assert_eq!("iIi".to_titlecase_lower_rest_in(Locale::Root), "Iii");
+    /// assert_eq!("iIi".to_titlecase_lower_rest_in(Locale::Azeri), "İıi");
+    /// assert_eq!("JÌS".to_titlecase_lower_rest_in(Locale::Lithuanian), "Ji\u{0307}\u{0300}s");
+    /// assert_eq!("IJSSELMEER".to_titlecase_lower_rest_in(Locale::Dutch), "IJsselmeer");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_lower_rest_in(&self, locale: Locale) -> String;
+
+    /// Titlecases the first cased char of every word in the given locale, leaves the rest unchanged,
+    /// and returns a copy. See [`StrTitleCase::to_titlecase_words`] for how words are found.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::{Locale, StrTitleCase};
+    /// assert_eq!("istanbul ve izmir".to_titlecase_words_in(Locale::Root), "Istanbul Ve Izmir");
+    /// assert_eq!("istanbul ve izmir".to_titlecase_words_in(Locale::Turkish), "İstanbul Ve İzmir");
+    /// assert_eq!("het ijs in ijmuiden".to_titlecase_words_in(Locale::Dutch), "Het IJs In IJmuiden");
+    /// assert_eq!("muintir na hÉireann".to_titlecase_words_in(Locale::Irish), "Muintir Na hÉireann");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_words_in(&self, locale: Locale) -> String;
+
+    /// Titlecases the first cased char of every word in the given locale, lowercases the rest of
+    /// each word in the same locale, and returns a copy. See [`StrTitleCase::to_titlecase_words`] for
+    /// how words are found.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::{Locale, StrTitleCase};
+    /// assert_eq!("KIRMIZI IŞIK".to_titlecase_words_lower_rest_in(Locale::Root), "Kirmizi Işik");
+    /// assert_eq!("KIRMIZI IŞIK".to_titlecase_words_lower_rest_in(Locale::Turkish), "Kırmızı Işık");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_words_lower_rest_in(&self, locale: Locale) -> String;
+
+    /// Titlecases the first cased char of every sentence, lowercases the rest of each sentence, and
+    /// returns a copy. Sentences are found using the Unicode sentence boundaries from
+    /// [UAX #29](https://www.unicode.org/reports/tr29/). Chars before the first cased char of a
+    /// sentence, such as an opening quote, are left as they are.
+    ///
+    /// UAX #29 does not end a sentence at a full stop followed by a lowercase word, since the full
+    /// stop may end an abbreviation. Text that needs sentence casing is often all lowercase, so a
+    /// full stop followed by whitespace always ends a sentence here. Closing quotes and brackets may
+    /// come between the full stop and the whitespace.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::StrTitleCase;
+    /// assert_eq!("hello WORLD. how are you? fine!".to_sentence_case(), "Hello world. How are you? Fine!");
+    /// assert_eq!("\"QUOTED.\" next one.".to_sentence_case(), "\"Quoted.\" Next one.");
+    /// assert_eq!("ΟΔΟΣ. ǄUNGLA".to_sentence_case(), "Οδος. ǅungla");
+    /// ```
+    /// A full stop that is not followed by whitespace does not end a sentence:
+    /// ```
+    /// use unicode_titlecase::StrTitleCase;
+    /// assert_eq!("pi is 3.14. (roughly.) see example.com".to_sentence_case(), "Pi is 3.14. (Roughly.) See example.com");
+    /// ```
+    /// # Locale
+    /// This function is not locale specific. Unicode special casing has rules for tr and az that
+    /// this function does not take into account. For tr and az locales use
+    /// [`StrTitleCase::to_sentence_case_tr_or_az`].
+    #[cfg(feature = "alloc")]
+    fn to_sentence_case(&self) -> String;
+
+    /// Titlecases the first cased char of every sentence, lowercases the rest of each sentence, and
+    /// returns a copy, using the tr and az casing rules. See [`StrTitleCase::to_sentence_case`] for
+    /// how sentences are found.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::StrTitleCase;
+    /// assert_eq!("IŞIK YANDI. istanbul UZAK.".to_sentence_case_tr_or_az(), "Işık yandı. İstanbul uzak.");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_sentence_case_tr_or_az(&self) -> String;
+
+    /// Titlecases the first cased char of every sentence in the given locale, lowercases the rest of
+    /// each sentence in the same locale, and returns a copy. See [`StrTitleCase::to_sentence_case`]
+    /// for how sentences are found.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::{Locale, StrTitleCase};
+    /// assert_eq!("KIRMIZI. ikinci.".to_sentence_case_in(Locale::Root), "Kirmizi. Ikinci.");
+    /// assert_eq!("KIRMIZI. ikinci.".to_sentence_case_in(Locale::Azeri), "Kırmızı. İkinci.");
+    /// assert_eq!("JÌS. ÌS.".to_sentence_case_in(Locale::Lithuanian), "Ji\u{0307}\u{0300}s. Ìs.");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_sentence_case_in(&self, locale: Locale) -> String;
+
     /// Tests if the first char of this string is titlecase. This is locale agnostic and returns the
     /// same values in the tr/az locales.
     /// # Returns
@@ -260,68 +887,571 @@ pub trait StrTitleCase {
     /// assert!(!"İİ".starts_titlecase_rest_lower());
     /// ```
     fn starts_titlecase_rest_lower(&self) -> bool;
+
+    /// Tests if every word of this string is titlecase, meaning
+    /// [`StrTitleCase::to_titlecase_words_lower_rest`] would return it unchanged. This is the Unicode
+    /// `isTitlecase(X)` definition: the first cased char of every word must be titlecase and the
+    /// rest of the word lowercase. Uncased chars, such as spaces, digits and punctuation, are
+    /// ignored, so a string without cased chars is titlecase. This is locale agnostic.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::StrTitleCase;
+    /// assert!("Hello World".is_titlecased_words());
+    /// assert!("\"Quoted\" Words, 42 Times".is_titlecased_words());
+    /// assert!("ǅungla".is_titlecased_words());
+    ///
+    /// assert!(!"Hello world".is_titlecased_words());
+    /// assert!(!"HELLO World".is_titlecased_words());
+    /// assert!(!"ǄUNGLA".is_titlecased_words());
+    /// ```
+    /// Strings without cased chars are titlecase. Combine with [`crate::is_cased`] to reject them:
+    /// ```
+    /// use unicode_titlecase::{is_cased, StrTitleCase};
+    /// assert!("123".is_titlecased_words());
+    /// assert!(!("123".is_titlecased_words() && "123".chars().any(is_cased)));
+    /// ```
+    fn is_titlecased_words(&self) -> bool;
+
+    /// Tests if every sentence of this string is sentence cased, meaning
+    /// [`StrTitleCase::to_sentence_case`] would return it unchanged. The first cased char of every
+    /// sentence must be titlecase and the rest of the sentence lowercase. Uncased chars are ignored,
+    /// so a string without cased chars is sentence cased. This is locale agnostic.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::StrTitleCase;
+    /// assert!("Hello world".is_sentence_cased());
+    /// assert!("Saved. Open it? Files left: 3.".is_sentence_cased());
+    ///
+    /// assert!(!"Hello World".is_sentence_cased());
+    /// assert!(!"Saved. open it?".is_sentence_cased());
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn is_sentence_cased(&self) -> bool;
+
+    /// Like [`StrTitleCase::to_titlecase`], but borrows this str instead of allocating when its
+    /// first char is already titlecase.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::StrTitleCase;
+    /// assert!(matches!("Abc".to_titlecase_cow(), Cow::Borrowed("Abc")));
+    /// assert!(matches!("abc".to_titlecase_cow(), Cow::Owned(s) if s == "Abc"));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_cow(&self) -> Cow<'_, str>;
+
+    /// Like [`StrTitleCase::to_titlecase_lower_rest`], but borrows this str instead of allocating
+    /// when it would be returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::StrTitleCase;
+    /// assert!(matches!("Abc def".to_titlecase_lower_rest_cow(), Cow::Borrowed(_)));
+    /// assert_eq!("ABC".to_titlecase_lower_rest_cow(), "Abc");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_lower_rest_cow(&self) -> Cow<'_, str>;
+
+    /// Like [`StrTitleCase::to_titlecase_tr_or_az`], but borrows this str instead of allocating when
+    /// it would be returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::StrTitleCase;
+    /// assert!(matches!("İstanbul".to_titlecase_tr_or_az_cow(), Cow::Borrowed(_)));
+    /// assert_eq!("istanbul".to_titlecase_tr_or_az_cow(), "İstanbul");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_tr_or_az_cow(&self) -> Cow<'_, str>;
+
+    /// Like [`StrTitleCase::to_titlecase_tr_or_az_lower_rest`], but borrows this str instead of
+    /// allocating when it would be returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::StrTitleCase;
+    /// assert!(matches!("Işık".to_titlecase_tr_or_az_lower_rest_cow(), Cow::Borrowed(_)));
+    /// assert_eq!("IŞIK".to_titlecase_tr_or_az_lower_rest_cow(), "Işık");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_tr_or_az_lower_rest_cow(&self) -> Cow<'_, str>;
+
+    /// Like [`StrTitleCase::to_titlecase_words`], but borrows this str instead of allocating when it
+    /// would be returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::StrTitleCase;
+    /// assert!(matches!("NASA's Mission".to_titlecase_words_cow(), Cow::Borrowed(_)));
+    /// assert_eq!("NASA's mission".to_titlecase_words_cow(), "NASA's Mission");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_words_cow(&self) -> Cow<'_, str>;
+
+    /// Like [`StrTitleCase::to_titlecase_words_lower_rest`], but borrows this str instead of
+    /// allocating when it would be returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::StrTitleCase;
+    /// assert!(matches!("The Quick Fox".to_titlecase_words_lower_rest_cow(), Cow::Borrowed(_)));
+    /// assert_eq!("THE QUICK FOX".to_titlecase_words_lower_rest_cow(), "The Quick Fox");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_words_lower_rest_cow(&self) -> Cow<'_, str>;
+
+    /// Like [`StrTitleCase::to_sentence_case`], but borrows this str instead of allocating when it
+    /// would be returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::StrTitleCase;
+    /// assert!(matches!("Saved. Open it?".to_sentence_case_cow(), Cow::Borrowed(_)));
+    /// assert_eq!("SAVED. OPEN IT?".to_sentence_case_cow(), "Saved. Open it?");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_sentence_case_cow(&self) -> Cow<'_, str>;
+
+    /// Like [`StrTitleCase::to_sentence_case_tr_or_az`], but borrows this str instead of allocating
+    /// when it would be returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::StrTitleCase;
+    /// assert!(matches!("Işık yandı.".to_sentence_case_tr_or_az_cow(), Cow::Borrowed(_)));
+    /// assert_eq!("IŞIK YANDI.".to_sentence_case_tr_or_az_cow(), "Işık yandı.");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_sentence_case_tr_or_az_cow(&self) -> Cow<'_, str>;
+
+    /// Like [`StrTitleCase::to_titlecase_in`], but borrows this str instead of allocating when it
+    /// would be returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::{Locale, StrTitleCase};
+    /// assert!(matches!("IJsselmeer".to_titlecase_in_cow(Locale::Dutch), Cow::Borrowed(_)));
+    /// assert_eq!("Ijsselmeer".to_titlecase_in_cow(Locale::Dutch), "IJsselmeer");
+    /// assert!(matches!("nGaeilge".to_titlecase_in_cow(Locale::Irish), Cow::Borrowed(_)));
+    /// assert_eq!("t-uisce".to_titlecase_in_cow(Locale::Irish), "tUisce");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_in_cow(&self, locale: Locale) -> Cow<'_, str>;
+
+    /// Like [`StrTitleCase::to_titlecase_lower_rest_in`], but borrows this str instead of allocating
+    /// when it would be returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::{Locale, StrTitleCase};
+    /// assert!(matches!("Işık".to_titlecase_lower_rest_in_cow(Locale::Azeri), Cow::Borrowed(_)));
+    /// assert_eq!("IJSSELMEER".to_titlecase_lower_rest_in_cow(Locale::Dutch), "IJsselmeer");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_lower_rest_in_cow(&self, locale: Locale) -> Cow<'_, str>;
+
+    /// Like [`StrTitleCase::to_titlecase_words_in`], but borrows this str instead of allocating when
+    /// it would be returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::{Locale, StrTitleCase};
+    /// assert!(matches!("Muintir Na hÉireann".to_titlecase_words_in_cow(Locale::Irish), Cow::Borrowed(_)));
+    /// assert_eq!("an t-uisce".to_titlecase_words_in_cow(Locale::Irish), "An tUisce");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_words_in_cow(&self, locale: Locale) -> Cow<'_, str>;
+
+    /// Like [`StrTitleCase::to_titlecase_words_lower_rest_in`], but borrows this str instead of
+    /// allocating when it would be returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::{Locale, StrTitleCase};
+    /// assert!(matches!("Het IJsselmeer".to_titlecase_words_lower_rest_in_cow(Locale::Dutch), Cow::Borrowed(_)));
+    /// assert_eq!("HET IJSSELMEER".to_titlecase_words_lower_rest_in_cow(Locale::Dutch), "Het IJsselmeer");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_words_lower_rest_in_cow(&self, locale: Locale) -> Cow<'_, str>;
+
+    /// Like [`StrTitleCase::to_sentence_case_in`], but borrows this str instead of allocating when it
+    /// would be returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::{Locale, StrTitleCase};
+    /// assert!(matches!("IJs is koud. Echt.".to_sentence_case_in_cow(Locale::Dutch), Cow::Borrowed(_)));
+    /// assert_eq!("IJS IS KOUD. ECHT.".to_sentence_case_in_cow(Locale::Dutch), "IJs is koud. Echt.");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_sentence_case_in_cow(&self, locale: Locale) -> Cow<'_, str>;
 }
 
 impl StrTitleCase for str {
+    #[cfg(feature = "alloc")]
     fn to_titlecase(&self) -> String {
-        let mut iter = self.chars();
-        iter.next()
-            .into_iter()
-            .flat_map(TitleCase::to_titlecase)
-            .chain(iter)
-            .collect()
+        self.to_titlecase_in(Locale::Root)
     }
 
+    #[cfg(feature = "alloc")]
     fn to_titlecase_lower_rest(&self) -> String {
-        let mut iter = self.chars();
-        iter.next()
-            .into_iter()
-            .flat_map(TitleCase::to_titlecase)
-            .chain(iter.flat_map(char::to_lowercase))
-            .collect()
+        self.to_titlecase_lower_rest_in(Locale::Root)
     }
 
+    #[cfg(feature = "alloc")]
     fn to_titlecase_tr_or_az(&self) -> String {
-        let mut iter = self.chars();
-        iter.next()
-            .into_iter()
-            .flat_map(TitleCase::to_titlecase_tr_or_az)
-            .chain(iter)
-            .collect()
+        self.to_titlecase_in(Locale::Turkish)
     }
 
+    #[cfg(feature = "alloc")]
     fn to_titlecase_tr_or_az_lower_rest(&self) -> String {
-        let mut iter = self.chars();
-        iter.next()
-            .into_iter()
-            .flat_map(TitleCase::to_titlecase_tr_or_az)
-            .chain(iter.map(to_lowercase_tr_or_az))
-            .collect()
+        self.to_titlecase_lower_rest_in(Locale::Turkish)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_words(&self) -> String {
+        self.to_titlecase_words_in(Locale::Root)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_words_lower_rest(&self) -> String {
+        self.to_titlecase_words_lower_rest_in(Locale::Root)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_in(&self, locale: Locale) -> String {
+        let mut result = String::with_capacity(self.len());
+        let rest = locale.push_titlecase_first(&mut result, self);
+        result.push_str(&self[rest..]);
+        result
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_lower_rest_in(&self, locale: Locale) -> String {
+        let mut result = String::with_capacity(self.len());
+        let rest = locale.push_titlecase_first(&mut result, self);
+        locale.push_lowercase(&mut result, self, rest..self.len());
+        result
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_words_in(&self, locale: Locale) -> String {
+        titlecase_words(self, locale, false)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_words_lower_rest_in(&self, locale: Locale) -> String {
+        titlecase_words(self, locale, true)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_sentence_case(&self) -> String {
+        self.to_sentence_case_in(Locale::Root)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_sentence_case_tr_or_az(&self) -> String {
+        self.to_sentence_case_in(Locale::Turkish)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_sentence_case_in(&self, locale: Locale) -> String {
+        sentence_case(self, locale)
     }
 
     fn starts_titlecase(&self) -> bool {
         self.chars()
             .next()
             .as_ref()
-            .map_or(false, TitleCase::is_titlecase)
+            .is_some_and(TitleCase::is_titlecase)
     }
 
     fn starts_titlecase_rest_lower(&self) -> bool {
         let mut iter = self.chars();
         iter.next()
             .as_ref()
-            .map_or(false, TitleCase::is_titlecase)
+            .is_some_and(TitleCase::is_titlecase)
             && iter.all(char::is_lowercase)
     }
+
+    fn is_titlecased_words(&self) -> bool {
+        titlecase_words_is_unchanged(self, Locale::Root, true)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn is_sentence_cased(&self) -> bool {
+        sentence_case_is_unchanged(self, Locale::Root)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_cow(&self) -> Cow<'_, str> {
+        self.to_titlecase_in_cow(Locale::Root)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_lower_rest_cow(&self) -> Cow<'_, str> {
+        self.to_titlecase_lower_rest_in_cow(Locale::Root)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_tr_or_az_cow(&self) -> Cow<'_, str> {
+        self.to_titlecase_in_cow(Locale::Turkish)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_tr_or_az_lower_rest_cow(&self) -> Cow<'_, str> {
+        self.to_titlecase_lower_rest_in_cow(Locale::Turkish)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_words_cow(&self) -> Cow<'_, str> {
+        self.to_titlecase_words_in_cow(Locale::Root)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_words_lower_rest_cow(&self) -> Cow<'_, str> {
+        self.to_titlecase_words_lower_rest_in_cow(Locale::Root)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_sentence_case_cow(&self) -> Cow<'_, str> {
+        self.to_sentence_case_in_cow(Locale::Root)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_sentence_case_tr_or_az_cow(&self) -> Cow<'_, str> {
+        self.to_sentence_case_in_cow(Locale::Turkish)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_in_cow(&self, locale: Locale) -> Cow<'_, str> {
+        let unchanged = titlecase_is_unchanged(self, locale, false);
+        borrow_if_unchanged(self, unchanged, |s| s.to_titlecase_in(locale))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_lower_rest_in_cow(&self, locale: Locale) -> Cow<'_, str> {
+        let unchanged = titlecase_is_unchanged(self, locale, true);
+        borrow_if_unchanged(self, unchanged, |s| s.to_titlecase_lower_rest_in(locale))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_words_in_cow(&self, locale: Locale) -> Cow<'_, str> {
+        let unchanged = titlecase_words_is_unchanged(self, locale, false);
+        borrow_if_unchanged(self, unchanged, |s| s.to_titlecase_words_in(locale))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_titlecase_words_lower_rest_in_cow(&self, locale: Locale) -> Cow<'_, str> {
+        let unchanged = titlecase_words_is_unchanged(self, locale, true);
+        borrow_if_unchanged(self, unchanged, |s| {
+            s.to_titlecase_words_lower_rest_in(locale)
+        })
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_sentence_case_in_cow(&self, locale: Locale) -> Cow<'_, str> {
+        let unchanged = sentence_case_is_unchanged(self, locale);
+        borrow_if_unchanged(self, unchanged, |s| s.to_sentence_case_in(locale))
+    }
+}
+
+/// Trait to titlecase a String in place. A char whose titlecase or lowercase mapping has the same
+/// UTF-8 length is overwritten without reallocating or moving the rest of the string. Only mappings
+/// to several chars, like 'ﬄ' to "Ffl", need a splice. Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+pub trait StringTitleCase {
+    /// Titlecases the first char of this String in place and leaves the rest unchanged. See
+    /// [`StrTitleCase::to_titlecase`].
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::StringTitleCase;
+    /// let mut s = String::from("ǆungla");
+    /// s.make_titlecase();
+    /// assert_eq!(s, "ǅungla");
+    ///
+    /// let mut s = String::from("ﬄoor");
+    /// s.make_titlecase();
+    /// assert_eq!(s, "Ffloor");
+    /// ```
+    /// # Locale
+    /// This function is not locale specific. For tr and az locales use
+    /// [`StringTitleCase::make_titlecase_tr_or_az`].
+    fn make_titlecase(&mut self);
+
+    /// Titlecases the first char of this String and lowercases the rest in place. See
+    /// [`StrTitleCase::to_titlecase_lower_rest`].
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::StringTitleCase;
+    /// let mut s = String::from("ΟΔΟΣ ΣΤΑΣΗΣ");
+    /// let capacity = s.capacity();
+    /// s.make_titlecase_lower_rest();
+    /// assert_eq!(s, "Οδος στασης");
+    /// assert_eq!(s.capacity(), capacity);
+    /// ```
+    /// # Locale
+    /// This function is not locale specific. For tr and az locales use
+    /// [`StringTitleCase::make_titlecase_tr_or_az_lower_rest`].
+    fn make_titlecase_lower_rest(&mut self);
+
+    /// Titlecases the first char of this String in place in the TR/AZ locale and leaves the rest
+    /// unchanged. See [`StrTitleCase::to_titlecase_tr_or_az`].
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::StringTitleCase;
+    /// let mut s = String::from("istanbul");
+    /// s.make_titlecase_tr_or_az();
+    /// assert_eq!(s, "İstanbul");
+    /// ```
+    fn make_titlecase_tr_or_az(&mut self);
+
+    /// Titlecases the first char of this String and lowercases the rest in place in the TR/AZ
+    /// locale. See [`StrTitleCase::to_titlecase_tr_or_az_lower_rest`].
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::StringTitleCase;
+    /// let mut s = String::from("IŞIK");
+    /// s.make_titlecase_tr_or_az_lower_rest();
+    /// assert_eq!(s, "Işık");
+    /// ```
+    fn make_titlecase_tr_or_az_lower_rest(&mut self);
+}
+
+#[cfg(feature = "alloc")]
+impl StringTitleCase for String {
+    fn make_titlecase(&mut self) {
+        make_titlecase_first(self, Locale::Root);
+    }
+
+    fn make_titlecase_lower_rest(&mut self) {
+        make_lowercase_from(self, first_char_len(self));
+        make_titlecase_first(self, Locale::Root);
+    }
+
+    fn make_titlecase_tr_or_az(&mut self) {
+        make_titlecase_first(self, Locale::Turkish);
+    }
+
+    fn make_titlecase_tr_or_az_lower_rest(&mut self) {
+        tr_az::make_lowercase_tr_az_from(self, first_char_len(self));
+        make_titlecase_first(self, Locale::Turkish);
+    }
 }
 
 /// A module to supply TR/AZ locale specific upper and lower case utilities.
 pub mod tr_az {
+    #[cfg(feature = "alloc")]
+    use alloc::borrow::Cow;
+    #[cfg(feature = "alloc")]
     use alloc::string::String;
     use core::fmt::{Display, Formatter, Result};
-    use core::iter::{FusedIterator, once};
+    use core::iter::{once, FusedIterator};
+
+    #[cfg(feature = "alloc")]
+    use core::ops::Range;
+
+    use crate::fold::fold_case_turkic;
+    use crate::{to_titlecase_in, CaseMappingIter, Locale};
+    #[cfg(feature = "alloc")]
+    use crate::ascii::ascii_prefix_len;
+    #[cfg(feature = "alloc")]
+    use crate::{
+        borrow_if_unchanged, combining_class, is_final_sigma, lowercases_to_self, replace_char,
+        StrCaseFold, StrLocaleCasing,
+    };
+
+    #[cfg(feature = "alloc")]
+    const COMBINING_DOT_ABOVE: char = '\u{0307}';
+
+    /// The canonical combining class of accents placed above a letter.
+    #[cfg(feature = "alloc")]
+    const ABOVE: u8 = 230;
+
+    /// Returns true if U+0307 COMBINING DOT ABOVE follows at the start of `after` with no
+    /// intervening char of class 0 or [`ABOVE`].
+    #[cfg(feature = "alloc")]
+    fn is_before_dot(after: &str) -> bool {
+        after
+            .chars()
+            .find(|&c| matches!(combining_class(c), 0 | ABOVE))
+            .is_some_and(|c| c == COMBINING_DOT_ABOVE)
+    }
+
+    /// Returns true if 'I' precedes the end of `before` with no intervening char of class 0 or
+    /// [`ABOVE`].
+    #[cfg(feature = "alloc")]
+    fn is_after_i(before: &str) -> bool {
+        before
+            .chars()
+            .rev()
+            .find(|&c| matches!(combining_class(c), 0 | ABOVE))
+            .is_some_and(|c| c == '\u{0049}')
+    }
+
+    /// Lowercases the chars of `s` in `range` and appends them to `out`, applying the
+    /// `Not_Before_Dot`, `After_I` and `Final_Sigma` rules. An 'I' followed by U+0307 COMBINING DOT
+    /// ABOVE is the decomposed form of 'İ', so the pair lowercases to 'i'. Only an 'I' inside
+    /// `range` removes a following dot, so a titlecased 'I' keeps the dot after it.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn push_lowercase_tr_az(out: &mut String, s: &str, range: Range<usize>) {
+        let start = range.start;
+        for (i, c) in s[range].char_indices() {
+            match c {
+                '\u{0049}' if is_before_dot(&s[start + i + c.len_utf8()..]) => out.push('\u{0069}'),
+                COMBINING_DOT_ABOVE if is_after_i(&s[start..start + i]) => {}
+                '\u{03A3}' if is_final_sigma(s, start + i) => out.push('\u{03C2}'),
+                _ => out.push(to_lowercase_tr_or_az(c)),
+            }
+        }
+    }
 
-    use crate::{CaseMappingIter, to_titlecase};
+    /// Lowercases `s` in place from byte `start` to the end, applying the same rules as
+    /// [`push_lowercase_tr_az`]. The dot after an 'I' is removed together with the 'I', since the
+    /// 'I' has already been lowercased when the dot is reached.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn make_lowercase_tr_az_from(s: &mut String, start: usize) {
+        let mut i = start;
+        loop {
+            let ascii_end = i + ascii_prefix_len(&s[i..], b"I");
+            s[i..ascii_end].make_ascii_lowercase();
+            i = ascii_end;
+            let Some(c) = s[i..].chars().next() else {
+                break;
+            };
+            let len = c.len_utf8();
+            i += match c {
+                '\u{0049}' if is_before_dot(&s[i + len..]) => {
+                    let dot = s[i + len..]
+                        .find(COMBINING_DOT_ABOVE)
+                        .map_or(i + len, |dot| i + len + dot);
+                    s.replace_range(dot..dot + COMBINING_DOT_ABOVE.len_utf8(), "");
+                    replace_char(s, i, len, once('\u{0069}'))
+                }
+                '\u{03A3}' if is_final_sigma(s, i) => replace_char(s, i, len, once('\u{03C2}')),
+                _ => replace_char(s, i, len, once(to_lowercase_tr_or_az(c))),
+            };
+        }
+    }
 
     /// Accepts a char and returns the Unicode upper case in the TR/AZ locale for that character as a an iterator.
     ///
@@ -357,6 +1487,11 @@ pub mod tr_az {
     /// then this function will have to change to an iterator and have a corresponding bump in the
     /// major version of the crate. A change like that seems unlikely enough to warrant this risk
     /// and optimization.
+    ///
+    /// # Context
+    /// An 'I' followed by U+0307 COMBINING DOT ABOVE lowercases to a single 'i'. That needs the
+    /// following chars, so it is only applied by [`StrTrAzCasing::to_lowercase_tr_az`].
+    #[allow(clippy::missing_panics_doc)]
     #[must_use]
     pub fn to_lowercase_tr_or_az(c: char) -> char {
         match c {
@@ -395,11 +1530,26 @@ pub mod tr_az {
     /// chars. To use locale agnostic version see [`to_titlecase`].
     #[must_use]
     pub fn to_titlecase_tr_or_az(c: char) -> [char; 3] {
-        if c == '\u{0069}' {
-            ['\u{0130}', '\0', '\0']
-        } else {
-            to_titlecase(c)
-        }
+        to_titlecase_in(c, Locale::Turkish)
+    }
+
+    /// Accepts a char and returns its Unicode full case folding in the TR/AZ locale as a 3 char
+    /// array. This applies the Turkic T mappings from CaseFolding.txt so that 'I' folds to 'ı' and
+    /// 'İ' folds to 'i'.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::tr_az::fold_case_tr_or_az;
+    /// assert_eq!(fold_case_tr_or_az('I'), ['ı', '\0', '\0']);
+    /// assert_eq!(fold_case_tr_or_az('İ'), ['i', '\0', '\0']);
+    /// assert_eq!(fold_case_tr_or_az('ß'), ['s', 's', '\0']);
+    /// ```
+    /// # Locale
+    /// This function is specific to the tr and az locales. To use the locale agnostic version see
+    /// [`crate::fold_case`].
+    #[must_use]
+    pub fn fold_case_tr_or_az(c: char) -> [char; 3] {
+        fold_case_turkic(c)
     }
 
     /// This trait provides functions to perform lower and upper case transformations on a char in
@@ -425,7 +1575,7 @@ pub mod tr_az {
 
         /// Returns true if this char is lowercase as defined by the Unicode standard and false otherwise.
         ///
-        /// This function is included for completeness. It is currently equivalent to char's is_lowercase().
+        /// This function is included for completeness. It is currently equivalent to char's `is_lowercase()`.
         fn is_lowercase_tr_az(&self) -> bool;
 
         /// Returns the Unicode upper case of this char in the TR/AZ locale as an iterator.
@@ -442,7 +1592,7 @@ pub mod tr_az {
 
         /// Returns true if this char is uppercase as defined by the Unicode standard and false otherwise.
         ///
-        /// This function is included for completeness. It is currently equivalent to char's is_uppercase().
+        /// This function is included for completeness. It is currently equivalent to char's `is_uppercase()`.
         fn is_uppercase_tr_az(&self) -> bool;
     }
 
@@ -465,7 +1615,7 @@ pub mod tr_az {
     }
 
     /// This trait provides functions to perform lower and upper case transformations on a str in
-    /// the TR/AZ locale.
+    /// the TR/AZ locale. The methods that return a String or a `Cow` require the `alloc` feature.
     pub trait StrTrAzCasing {
         /// Returns the Unicode lower case of this str in the TR/AZ locale as a new String.
         ///
@@ -474,6 +1624,20 @@ pub mod tr_az {
         /// use unicode_titlecase::tr_az::StrTrAzCasing;
         /// assert_eq!("İIAb".to_lowercase_tr_az(), "iıab");
         /// ```
+        /// A capital sigma at the end of a word lowercases to the final form 'ς':
+        /// ```
+        /// use unicode_titlecase::tr_az::StrTrAzCasing;
+        /// assert_eq!("ΟΔΟΣ".to_lowercase_tr_az(), "οδος");
+        /// ```
+        /// The decomposed 'İ', an 'I' followed by U+0307 COMBINING DOT ABOVE, lowercases to 'i'. Other
+        /// accents below the 'I' may come between them:
+        /// ```
+        /// use unicode_titlecase::tr_az::StrTrAzCasing;
+        /// assert_eq!("I\u{0307}ZMI\u{0307}R".to_lowercase_tr_az(), "izmir");
+        /// assert_eq!("I\u{0323}\u{0307}".to_lowercase_tr_az(), "i\u{0323}");
+        /// assert_eq!("I\u{0300}\u{0307}".to_lowercase_tr_az(), "ı\u{0300}\u{0307}");
+        /// ```
+        #[cfg(feature = "alloc")]
         fn to_lowercase_tr_az(&self) -> String;
 
         /// Returns true if every char in this str is lowercase, false otherwise.
@@ -491,6 +1655,7 @@ pub mod tr_az {
         /// The U+0307 char is not considered lowercase. To avoid this issue do not mix the tr/az and locale agnostic functions.
         /// ```
         /// use unicode_titlecase::tr_az::StrTrAzCasing;
+        /// # #[cfg(feature = "alloc")]
         /// assert!("İ".to_lowercase_tr_az().is_lowercase_tr_az()); //Using TR/AZ lowercasing
         /// assert!(!"İ".to_lowercase().is_lowercase_tr_az()); //WRONG: Using std lib lowercasing
         /// ```
@@ -503,6 +1668,7 @@ pub mod tr_az {
         /// use unicode_titlecase::tr_az::StrTrAzCasing;
         /// assert_eq!("iıab".to_uppercase_tr_az(), "İIAB");
         /// ```
+        #[cfg(feature = "alloc")]
         fn to_uppercase_tr_az(&self) -> String;
 
         /// Returns true if every char in this str is uppercase, false otherwise.
@@ -515,24 +1681,153 @@ pub mod tr_az {
         /// ```
         ///
         fn is_uppercase_tr_az(&self) -> bool;
+
+        /// Returns the Unicode case folding of this str in the TR/AZ locale as a new String.
+        ///
+        /// # Examples
+        /// ```
+        /// use unicode_titlecase::tr_az::StrTrAzCasing;
+        /// assert_eq!("KIRMIZI".to_case_folded_tr_az(), "kırmızı");
+        /// assert_eq!("İZMİR".to_case_folded_tr_az(), "izmir");
+        /// assert_eq!("KIRMIZI".to_case_folded_tr_az(), "kırmızı".to_case_folded_tr_az());
+        /// ```
+        #[cfg(feature = "alloc")]
+        fn to_case_folded_tr_az(&self) -> String;
+
+        /// Like [`StrTrAzCasing::to_lowercase_tr_az`], but borrows this str instead of allocating
+        /// when every char is already lowercase or uncased.
+        ///
+        /// # Examples
+        /// ```
+        /// use std::borrow::Cow;
+        /// use unicode_titlecase::tr_az::StrTrAzCasing;
+        /// assert!(matches!("ıi ab".to_lowercase_tr_az_cow(), Cow::Borrowed(_)));
+        /// assert_eq!("İIAb".to_lowercase_tr_az_cow(), "iıab");
+        /// ```
+        #[cfg(feature = "alloc")]
+        fn to_lowercase_tr_az_cow(&self) -> Cow<'_, str>;
+
+        /// Like [`StrTrAzCasing::to_uppercase_tr_az`], but borrows this str instead of allocating
+        /// when every char is already uppercase or uncased.
+        ///
+        /// # Examples
+        /// ```
+        /// use std::borrow::Cow;
+        /// use unicode_titlecase::tr_az::StrTrAzCasing;
+        /// assert!(matches!("İI AB".to_uppercase_tr_az_cow(), Cow::Borrowed(_)));
+        /// assert_eq!("iıab".to_uppercase_tr_az_cow(), "İIAB");
+        /// ```
+        #[cfg(feature = "alloc")]
+        fn to_uppercase_tr_az_cow(&self) -> Cow<'_, str>;
+
+        /// Like [`StrTrAzCasing::to_case_folded_tr_az`], but borrows this str instead of allocating
+        /// when it is already case folded.
+        ///
+        /// # Examples
+        /// ```
+        /// use std::borrow::Cow;
+        /// use unicode_titlecase::tr_az::StrTrAzCasing;
+        /// assert!(matches!("kırmızı".to_case_folded_tr_az_cow(), Cow::Borrowed(_)));
+        /// assert_eq!("KIRMIZI".to_case_folded_tr_az_cow(), "kırmızı");
+        /// ```
+        #[cfg(feature = "alloc")]
+        fn to_case_folded_tr_az_cow(&self) -> Cow<'_, str>;
     }
 
     impl StrTrAzCasing for str {
+        #[cfg(feature = "alloc")]
         fn to_lowercase_tr_az(&self) -> String {
-            self.chars().map(to_lowercase_tr_or_az).collect()
+            self.to_lowercase_in(Locale::Turkish)
         }
 
         fn is_lowercase_tr_az(&self) -> bool {
             self.chars().all(|c| c.is_lowercase_tr_az())
         }
 
+        #[cfg(feature = "alloc")]
         fn to_uppercase_tr_az(&self) -> String {
-            self.chars().flat_map(to_uppercase_tr_or_az).collect()
+            self.to_uppercase_in(Locale::Turkish)
         }
 
         fn is_uppercase_tr_az(&self) -> bool {
             self.chars().all(|c| c.is_uppercase_tr_az())
         }
+
+        #[cfg(feature = "alloc")]
+        fn to_case_folded_tr_az(&self) -> String {
+            self.to_case_folded_in(Locale::Turkish)
+        }
+
+        #[cfg(feature = "alloc")]
+        fn to_lowercase_tr_az_cow(&self) -> Cow<'_, str> {
+            let unchanged = self.chars().all(|c| lowercases_to_self(c, Locale::Turkish));
+            borrow_if_unchanged(self, unchanged, str::to_lowercase_tr_az)
+        }
+
+        #[cfg(feature = "alloc")]
+        fn to_uppercase_tr_az_cow(&self) -> Cow<'_, str> {
+            let unchanged = Locale::Turkish.uppercase_is_unchanged(self);
+            borrow_if_unchanged(self, unchanged, str::to_uppercase_tr_az)
+        }
+
+        #[cfg(feature = "alloc")]
+        fn to_case_folded_tr_az_cow(&self) -> Cow<'_, str> {
+            self.to_case_folded_in_cow(Locale::Turkish)
+        }
+    }
+
+    /// Trait to lower and upper case a String in place in the TR/AZ locale. A char whose mapping has
+    /// the same UTF-8 length is overwritten without reallocating or moving the rest of the string.
+    /// Requires the `alloc` feature.
+    #[cfg(feature = "alloc")]
+    pub trait StringTrAzCasing {
+        /// Lowercases this String in place in the TR/AZ locale. See
+        /// [`StrTrAzCasing::to_lowercase_tr_az`].
+        ///
+        /// # Examples
+        /// ```
+        /// use unicode_titlecase::tr_az::StringTrAzCasing;
+        /// let mut s = String::from("KIRMIZI İZMİR");
+        /// s.make_lowercase_tr_az();
+        /// assert_eq!(s, "kırmızı izmir");
+        ///
+        /// let mut s = String::from("I\u{0307}ZMI\u{0307}R");
+        /// s.make_lowercase_tr_az();
+        /// assert_eq!(s, "izmir");
+        /// ```
+        fn make_lowercase_tr_az(&mut self);
+
+        /// Uppercases this String in place in the TR/AZ locale. See
+        /// [`StrTrAzCasing::to_uppercase_tr_az`].
+        ///
+        /// # Examples
+        /// ```
+        /// use unicode_titlecase::tr_az::StringTrAzCasing;
+        /// let mut s = String::from("kırmızı izmir");
+        /// s.make_uppercase_tr_az();
+        /// assert_eq!(s, "KIRMIZI İZMİR");
+        /// ```
+        fn make_uppercase_tr_az(&mut self);
+    }
+
+    #[cfg(feature = "alloc")]
+    impl StringTrAzCasing for String {
+        fn make_lowercase_tr_az(&mut self) {
+            make_lowercase_tr_az_from(self, 0);
+        }
+
+        fn make_uppercase_tr_az(&mut self) {
+            let mut i = 0;
+            loop {
+                let ascii_end = i + ascii_prefix_len(&self[i..], b"i");
+                self[i..ascii_end].make_ascii_uppercase();
+                i = ascii_end;
+                let Some(c) = self[i..].chars().next() else {
+                    break;
+                };
+                i += replace_char(self, i, c.len_utf8(), to_uppercase_tr_or_az(c));
+            }
+        }
     }
 
     /// An iterator over a char that had its casing changed.
@@ -699,8 +1994,38 @@ impl Display for CaseMappingIter {
 }
 
 #[cfg(test)]
+#[allow(clippy::needless_for_each)]
 mod tests {
-    include!(concat!(env!("OUT_DIR"), "/casing.rs"));
+    use super::{changes_when_titlecased, is_cased, to_titlecase};
+
+    include!(concat!(env!("OUT_DIR"), "/titlecase_table.rs"));
+
+    #[test]
+    fn trie_matches_table() {
+        for c in (0..=u32::from(char::MAX)).filter_map(char::from_u32) {
+            let expected = TITLECASE_TABLE
+                .binary_search_by(|&(key, _)| key.cmp(&c))
+                .map_or([c, '\0', '\0'], |index| TITLECASE_TABLE[index].1);
+            assert_eq!(to_titlecase(c), expected, "For code point: {c}");
+            assert_eq!(changes_when_titlecased(c), expected[0] != c, "For code point: {c}");
+        }
+    }
+
+    #[test]
+    fn cased() {
+        // Other_Lowercase and Other_Uppercase chars, which are not Ll or Lu
+        for c in ['ª', 'ʰ', '\u{0345}', 'ⁿ', 'ⅰ', 'Ⅰ', 'ⓐ', 'Ⓐ', '🄰'] {
+            assert!(is_cased(c), "For code point: {c}");
+        }
+        for c in ['ʹ', '\u{0301}', '①', '字'] {
+            assert!(!is_cased(c), "For code point: {c}");
+        }
+        for c in (0..=u32::from(char::MAX)).filter_map(char::from_u32) {
+            if changes_when_titlecased(c) {
+                assert!(is_cased(c), "For code point: {c}");
+            }
+        }
+    }
 
     #[test]
     fn self_mapping() {
@@ -717,4 +2042,323 @@ mod tests {
             last = *cp;
         });
     }
-}
\ No newline at end of file
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn final_sigma() {
+        use crate::tr_az::StrTrAzCasing;
+        use crate::StrTitleCase;
+
+        // End of the string
+        assert_eq!("ΟΔΟΣ".to_titlecase_lower_rest(), "Οδος");
+        assert_eq!("ΟΔΟΣ".to_titlecase_tr_or_az_lower_rest(), "Οδος");
+        assert_eq!("ΟΔΟΣ".to_lowercase_tr_az(), "οδος");
+        // Before punctuation and spaces
+        assert_eq!("ΟΔΟΣ, ΟΔΟΣ.".to_titlecase_lower_rest(), "Οδος, οδος.");
+        assert_eq!("ΟΔΟΣ! ΟΔΟΣ".to_titlecase_words_lower_rest(), "Οδος! Οδος");
+        assert_eq!("«ΟΔΟΣ»".to_lowercase_tr_az(), "«οδος»");
+        // Inside a word
+        assert_eq!("ΣΙΣΥΦΟΣ".to_titlecase_lower_rest(), "Σισυφος");
+        assert_eq!("ΟΔΟΣΟΣ".to_lowercase_tr_az(), "οδοσος");
+        // Case-ignorable chars are skipped when looking for a cased neighbour
+        assert_eq!("ΑΣ'Α".to_titlecase_lower_rest(), "Ασ'α");
+        assert_eq!("Α'Σ".to_titlecase_lower_rest(), "Α'ς");
+        assert_eq!("ΑΣ\u{0301}".to_lowercase_tr_az(), "ας\u{0301}");
+        // No cased char before it
+        assert_eq!("Σ".to_lowercase_tr_az(), "σ");
+        assert_eq!("A Σ".to_titlecase_lower_rest(), "A σ");
+        assert_eq!("1Σ".to_titlecase_words_lower_rest(), "1Σ");
+        assert_eq!("1ΑΣ".to_titlecase_words_lower_rest(), "1Ας");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn tr_az_dot_above() {
+        use crate::tr_az::StrTrAzCasing;
+        use crate::StrTitleCase;
+
+        // The decomposed 'İ' lowercases to 'i'
+        assert_eq!("I\u{0307}".to_lowercase_tr_az(), "i");
+        assert_eq!("KIRMIZI I\u{0307}ZMI\u{0307}R".to_lowercase_tr_az(), "kırmızı izmir");
+        assert_eq!(
+            "I\u{0307}STANBUL".to_titlecase_words_lower_rest_in(crate::Locale::Azeri),
+            "I\u{0307}stanbul"
+        );
+        // Accents below may come between the 'I' and the dot, but accents above block it
+        assert_eq!("I\u{0316}\u{0307}".to_lowercase_tr_az(), "i\u{0316}");
+        assert_eq!("I\u{0301}\u{0307}".to_lowercase_tr_az(), "ı\u{0301}\u{0307}");
+        assert_eq!("IA\u{0307}".to_lowercase_tr_az(), "ıa\u{0307}");
+        // A dot after a lowercase 'i' or an 'I' outside the lowercased range is kept
+        assert_eq!("i\u{0307}".to_lowercase_tr_az(), "i\u{0307}");
+        assert_eq!("I\u{0307}".to_titlecase_tr_or_az_lower_rest(), "I\u{0307}");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn dutch_ij() {
+        use crate::{HeadlineCaser, Locale, StrTitleCase};
+
+        // Both letters of the digraph are capitalized, whatever their case
+        for s in ["ijsselmeer", "Ijsselmeer", "iJsselmeer", "IJsselmeer"] {
+            assert_eq!(s.to_titlecase_in(Locale::Dutch), "IJsselmeer");
+        }
+        assert_eq!("IJSSELMEER".to_titlecase_lower_rest_in(Locale::Dutch), "IJsselmeer");
+        assert_eq!("ij".to_titlecase_in(Locale::Dutch), "IJ");
+        assert_eq!("íjs".to_titlecase_in(Locale::Dutch), "ÍJs");
+        assert_eq!("i\u{0301}j\u{0301}s".to_titlecase_in(Locale::Dutch), "I\u{0301}J\u{0301}s");
+        assert_eq!("i\u{0301}n".to_titlecase_in(Locale::Dutch), "I\u{0301}n");
+        // The precomposed ligature
+        assert_eq!("ĳsselmeer".to_titlecase_in(Locale::Dutch), "Ĳsselmeer");
+        assert_eq!("ĲSSELMEER".to_titlecase_lower_rest_in(Locale::Dutch), "Ĳsselmeer");
+        // Only at the start of a word, and not in other locales
+        assert_eq!("bijna".to_titlecase_words_in(Locale::Dutch), "Bijna");
+        assert_eq!("in".to_titlecase_in(Locale::Dutch), "In");
+        assert_eq!("i".to_titlecase_in(Locale::Dutch), "I");
+        assert_eq!("ijsselmeer".to_titlecase_in(Locale::Root), "Ijsselmeer");
+        // Word by word, sentence and headline casing
+        assert_eq!(
+            "HET IJS, (ijzer) EN ĳs".to_titlecase_words_lower_rest_in(Locale::Dutch),
+            "Het IJs, (IJzer) En Ĳs"
+        );
+        assert_eq!(
+            "ijs is koud. ijzer niet.".to_sentence_case_in(Locale::Dutch),
+            "IJs is koud. IJzer niet."
+        );
+        assert_eq!(
+            HeadlineCaser::new(&["de"], usize::MAX)
+                .with_locale(Locale::Dutch)
+                .to_headline_case("ijsbeer op de ijsvlakte"),
+            "IJsbeer Op de IJsvlakte"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn irish_mutations() {
+        use crate::{HeadlineCaser, Locale, StrTitleCase};
+
+        // Eclipsis and 't' before 's'
+        for (s, expected) in [
+            ("bpáirc", "bPáirc"),
+            ("dtír", "dTír"),
+            ("gcathair", "gCathair"),
+            ("mbád", "mBád"),
+            ("ndoras", "nDoras"),
+            ("ngaeilge", "nGaeilge"),
+            ("bhfuil", "bhFuil"),
+            ("tsráid", "tSráid"),
+        ] {
+            assert_eq!(s.to_titlecase_in(Locale::Irish), expected);
+            assert_eq!(expected.to_titlecase_in(Locale::Irish), expected);
+            assert_eq!(s.to_titlecase_in(Locale::Root), s.to_titlecase());
+        }
+        // 'h' before a capitalized vowel, and hyphenated 'n' and 't', which lose the hyphen
+        assert_eq!("hÉireann".to_titlecase_in(Locale::Irish), "hÉireann");
+        assert_eq!("hÉIREANN".to_titlecase_lower_rest_in(Locale::Irish), "hÉireann");
+        assert_eq!("n-athair".to_titlecase_in(Locale::Irish), "nAthair");
+        assert_eq!("t-uisce".to_titlecase_lower_rest_in(Locale::Irish), "tUisce");
+        assert_eq!("nAthair".to_titlecase_in(Locale::Irish), "nAthair");
+        assert_eq!("tUISCE".to_titlecase_lower_rest_in(Locale::Irish), "tUisce");
+        // Words that only look like prefixes are titlecased as usual
+        for s in [
+            "nuair", "tá", "trá", "bean", "gaeilge", "n-", "t-", "h", "dún", "hata", "halla",
+            "hotel", "héireann",
+        ] {
+            assert_eq!(s.to_titlecase_in(Locale::Irish), s.to_titlecase(), "For {s:?}");
+        }
+        assert_eq!("NGAEILGE".to_titlecase_lower_rest_in(Locale::Irish), "Ngaeilge");
+        assert_eq!("nGAEILGE".to_titlecase_lower_rest_in(Locale::Irish), "nGaeilge");
+        // Word by word, sentence and headline casing
+        assert_eq!(
+            "i ngaillimh, an t-uisce agus na hÉireann".to_titlecase_words_in(Locale::Irish),
+            "I nGaillimh, An tUisce Agus Na hÉireann"
+        );
+        assert_eq!(
+            "I NGAILLIMH AN T-UISCE".to_titlecase_words_lower_rest_in(Locale::Irish),
+            "I Ngaillimh An T-Uisce"
+        );
+        assert_eq!("n-a t-".to_titlecase_words_in(Locale::Root), "N-A T-");
+        assert_eq!(
+            "ngaeilge. bhfuil sé.".to_sentence_case_in(Locale::Irish),
+            "nGaeilge. bhFuil sé."
+        );
+        assert_eq!(
+            HeadlineCaser::new(&["na", "an"], usize::MAX)
+                .with_locale(Locale::Irish)
+                .to_headline_case("rialtas na hÉireann agus an t-uisce"),
+            "Rialtas na hÉireann Agus an tUisce"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn german_capital_sharp_s() {
+        use crate::{Locale, StrLocaleCasing, StrTitleCase, TitleCase};
+
+        let de = Locale::GermanCapitalSharpS;
+        assert_eq!("Straße".to_uppercase_in(de), "STRAẞE");
+        assert_eq!("GROẞE straße".to_uppercase_in(de), "GROẞE STRAẞE");
+        assert_eq!("ß".to_titlecase_in(de), "ẞ");
+        assert_eq!("ßa".to_titlecase_lower_rest_in(de), "ẞa");
+        assert!('ß'.to_titlecase_in(de).eq(['ẞ']));
+        assert_eq!("ß straße".to_titlecase_words_lower_rest_in(de), "ẞ Straße");
+        // 'ẞ' lowercases to 'ß' in every locale, and the rest of a titlecased word keeps its 'ß'
+        assert_eq!("STRAẞE".to_titlecase_lower_rest_in(de), "Straße");
+        assert_eq!("STRAẞE".to_lowercase_in(Locale::Root), "straße");
+        assert_eq!("straße".to_titlecase_in(de), "Straße");
+        // The default is unchanged
+        assert_eq!("Straße".to_uppercase_in(Locale::Root), "STRASSE");
+        assert_eq!("ß".to_titlecase_in(Locale::Root), "Ss");
+        assert_eq!(Locale::from_language_tag("de"), Locale::Root);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn armenian_ech_yiwn() {
+        use crate::{HeadlineCaser, Locale, StrLocaleCasing, StrTitleCase, TitleCase};
+
+        let hy = Locale::Armenian;
+        assert_eq!("և".to_titlecase_in(hy), "Եվ");
+        assert_eq!("և".to_uppercase_in(hy), "ԵՎ");
+        assert!('և'.to_titlecase_in(hy).eq(['Ե', 'վ']));
+        assert_eq!("Երևան".to_uppercase_in(hy), "ԵՐԵՎԱՆ");
+        assert_eq!("ևս".to_titlecase_lower_rest_in(hy), "Եվս");
+        assert_eq!("երևան և գյումրի".to_titlecase_words_in(hy), "Երևան Եվ Գյումրի");
+        assert_eq!("և ԵՐԵՎԱՆ.".to_sentence_case_in(hy), "Եվ երեվան.");
+        assert_eq!(
+            HeadlineCaser::new(&[], usize::MAX)
+                .with_locale(hy)
+                .to_headline_case("և"),
+            "Եվ"
+        );
+        // The lowercase is unchanged, and the classical spelling is the default
+        assert_eq!("ԵՐԵՎԱՆ".to_lowercase_in(hy), "երեվան");
+        assert_eq!("և".to_titlecase_in(Locale::Root), "Եւ");
+        assert_eq!("և".to_uppercase_in(Locale::Root), "ԵՒ");
+        assert_eq!("և".to_titlecase(), "Եւ");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn cow_matches_owned() {
+        use crate::tr_az::StrTrAzCasing;
+        use crate::StrTitleCase;
+
+        let inputs = [
+            "", "a", "A", "Abc", "ABC", "1abc", "Hello World", "Hello world. Bye.", "ǅungla",
+            "ǄUNGLA", "ﬄoor", "Οδος", "ΟΔΟΣ", "Işık", "IŞIK", "İstanbul", "I\u{0307}", "ϒ ϒ",
+            "\"Quoted.\" Next", "kırmızı",
+        ];
+        for s in inputs {
+            assert_eq!(s.to_titlecase_cow(), s.to_titlecase(), "{s}");
+            assert_eq!(s.to_titlecase_lower_rest_cow(), s.to_titlecase_lower_rest(), "{s}");
+            assert_eq!(s.to_titlecase_tr_or_az_cow(), s.to_titlecase_tr_or_az(), "{s}");
+            assert_eq!(
+                s.to_titlecase_tr_or_az_lower_rest_cow(),
+                s.to_titlecase_tr_or_az_lower_rest(),
+                "{s}"
+            );
+            assert_eq!(s.to_titlecase_words_cow(), s.to_titlecase_words(), "{s}");
+            assert_eq!(
+                s.to_titlecase_words_lower_rest_cow(),
+                s.to_titlecase_words_lower_rest(),
+                "{s}"
+            );
+            assert_eq!(s.to_sentence_case_cow(), s.to_sentence_case(), "{s}");
+            assert_eq!(s.to_sentence_case_tr_or_az_cow(), s.to_sentence_case_tr_or_az(), "{s}");
+            assert_eq!(s.to_lowercase_tr_az_cow(), s.to_lowercase_tr_az(), "{s}");
+            assert_eq!(s.to_uppercase_tr_az_cow(), s.to_uppercase_tr_az(), "{s}");
+            assert_eq!(s.to_case_folded_tr_az_cow(), s.to_case_folded_tr_az(), "{s}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn cow_in_matches_owned() {
+        use crate::{Locale, StrCaseFold, StrLocaleCasing, StrTitleCase};
+
+        let locales = [
+            Locale::Root,
+            Locale::Turkish,
+            Locale::Azeri,
+            Locale::Lithuanian,
+            Locale::Dutch,
+            Locale::Irish,
+            Locale::Greek,
+            Locale::GermanCapitalSharpS,
+            Locale::Armenian,
+        ];
+        let inputs = [
+            "", "Abc", "ABC", "Hello world. Bye.", "ǅungla", "ﬄoor", "ΟΔΟΣ", "Işık", "İstanbul",
+            "I\u{0307}", "IJsselmeer", "Ijsselmeer", "IJSSELMEER", "Het IJsselmeer", "Íjs", "nGaeilge",
+            "t-uisce", "tUisce", "hÉireann", "An t-Uisce", "i\u{0307}\u{0300}s", "I\u{0300}s",
+            "\u{1D62}\u{0307}", "Ji\u{0307}\u{0300}s", "E\u{0307}", "Καλημέρα", "ΚΑΛΗΜΕΡΑ", "ΠΡΟΪΟΝ",
+            "Ή", "ΑΙ", "ΣΫ\u{0308}", "Straße", "STRAẞE", "Երևան", "ԵՐԵՎԱՆ", "strasse",
+        ];
+        for locale in locales {
+            for s in inputs {
+                let context = (s, locale);
+                assert_eq!(s.to_titlecase_in_cow(locale), s.to_titlecase_in(locale), "{context:?}");
+                assert_eq!(
+                    s.to_titlecase_lower_rest_in_cow(locale),
+                    s.to_titlecase_lower_rest_in(locale),
+                    "{context:?}"
+                );
+                assert_eq!(
+                    s.to_titlecase_words_in_cow(locale),
+                    s.to_titlecase_words_in(locale),
+                    "{context:?}"
+                );
+                assert_eq!(
+                    s.to_titlecase_words_lower_rest_in_cow(locale),
+                    s.to_titlecase_words_lower_rest_in(locale),
+                    "{context:?}"
+                );
+                assert_eq!(
+                    s.to_sentence_case_in_cow(locale),
+                    s.to_sentence_case_in(locale),
+                    "{context:?}"
+                );
+                assert_eq!(s.to_lowercase_in_cow(locale), s.to_lowercase_in(locale), "{context:?}");
+                assert_eq!(s.to_uppercase_in_cow(locale), s.to_uppercase_in(locale), "{context:?}");
+                assert_eq!(
+                    s.to_case_folded_in_cow(locale),
+                    s.to_case_folded_in(locale),
+                    "{context:?}"
+                );
+            }
+        }
+        for s in inputs {
+            assert_eq!(s.to_case_folded_simple_cow(), s.to_case_folded_simple(), "{s}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn in_place_matches_owned() {
+        use crate::tr_az::{StrTrAzCasing, StringTrAzCasing};
+        use crate::{StrTitleCase, StringTitleCase};
+        use alloc::string::String;
+
+        let inputs = [
+            "", "a", "ﬄoor", "ǆungla", "ΟΔΟΣ ΣΤΑΣΗΣ", "Α'Σ", "IŞIK", "İstanbul", "I\u{0307}ZMIR",
+            "I\u{0316}\u{0307}I\u{0301}\u{0307}", "iİ ß ŉ", "\u{0345}Σ",
+        ];
+        for s in inputs {
+            let check = |make: fn(&mut String), expected: String| {
+                let mut owned = String::from(s);
+                make(&mut owned);
+                assert_eq!(owned, expected, "{s}");
+            };
+            check(String::make_titlecase, s.to_titlecase());
+            check(String::make_titlecase_lower_rest, s.to_titlecase_lower_rest());
+            check(String::make_titlecase_tr_or_az, s.to_titlecase_tr_or_az());
+            check(
+                String::make_titlecase_tr_or_az_lower_rest,
+                s.to_titlecase_tr_or_az_lower_rest(),
+            );
+            check(String::make_lowercase_tr_az, s.to_lowercase_tr_az());
+            check(String::make_uppercase_tr_az, s.to_uppercase_tr_az());
+        }
+    }
+}
@@ -0,0 +1,484 @@
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use core::ops::Range;
+
+use unicode_segmentation::{UWordBoundIndices, UnicodeSegmentation};
+
+#[cfg(feature = "alloc")]
+use crate::ascii::push_mapped;
+#[cfg(feature = "alloc")]
+use crate::el::push_uppercase_el;
+#[cfg(feature = "alloc")]
+use crate::el::uppercase_el_is_unchanged;
+use crate::lt::titlecase_first_lt_unchanged;
+#[cfg(feature = "alloc")]
+use crate::lt::uppercase_lt_is_unchanged;
+#[cfg(feature = "alloc")]
+use crate::lt::{push_lowercase_lt, push_titlecase_first_lt, push_without_soft_dots};
+#[cfg(feature = "alloc")]
+use crate::tr_az::{push_lowercase_tr_az, to_uppercase_tr_or_az};
+#[cfg(feature = "alloc")]
+use crate::{
+    borrow_if_unchanged, lowercases_to_self, push_lowercase, CaseMappingIter, ToTitleCase,
+};
+use crate::{combining_class, to_titlecase, uppercases_to_self};
+
+/// The locales with casing rules that differ from the Unicode defaults. Every casing function that
+/// ends in `_in` takes one of these to select its rules.
+///
+/// New variants may be added as more tailorings are supported, so matches on this enum need a
+/// wildcard arm.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::{to_titlecase_in, Locale, StrTitleCase};
+/// assert_eq!(to_titlecase_in('i', Locale::Root), ['I', '\0', '\0']);
+/// assert_eq!(to_titlecase_in('i', Locale::Turkish), ['İ', '\0', '\0']);
+/// # #[cfg(feature = "alloc")]
+/// assert_eq!("istanbul".to_titlecase_in(Locale::Azeri), "İstanbul");
+/// ```
+#[derive(Debug, Clone, Copy, Default, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Locale {
+    /// The locale agnostic Unicode casing rules. This is what the functions without a locale use.
+    #[default]
+    Root,
+    /// Turkish (tr). 'i' and 'I' are different letters from 'İ' and 'ı'.
+    Turkish,
+    /// Azeri (az). Uses the same casing rules as Turkish.
+    Azeri,
+    /// Lithuanian (lt). 'i' and 'j' keep their dot when they carry another accent above.
+    Lithuanian,
+    /// Dutch (nl). The 'ij' digraph at the start of a word is titlecased as a unit, to "IJ".
+    Dutch,
+    /// Irish (ga). The prefixes of initial mutations stay lowercase when a word is titlecased, as in
+    /// "nGaeilge" and "hÉireann". An 'h' is only taken as a prefix if the vowel after it is already
+    /// capitalized, since words such as "hata" start with it.
+    Irish,
+    /// Greek (el). Accents are removed when uppercasing, so "Καλημέρα" becomes "ΚΑΛΗΜΕΡΑ".
+    Greek,
+    /// German (de) with the capital sharp s that German spelling has allowed since 2017. 'ß'
+    /// uppercases and titlecases to 'ẞ' rather than "SS". It is never selected from a language
+    /// tag, since "SS" is still the usual spelling, so "de" selects [`Locale::Root`].
+    GermanCapitalSharpS,
+    /// Eastern Armenian (hy) in the reformed spelling. The ech-yiwn ligature 'և' titlecases to "Եվ"
+    /// and uppercases to "ԵՎ". [`Locale::Root`] keeps the classical "Եւ" and "ԵՒ" of the Unicode
+    /// defaults, which Western Armenian (hyw) also uses.
+    Armenian,
+}
+
+impl Locale {
+    /// Selects the casing rules for a [BCP 47](https://www.rfc-editor.org/info/bcp47) language tag
+    /// such as "tr-TR" or "az-Latn-AZ". Only the language subtag changes the casing rules, so script,
+    /// region, variant and extension subtags are accepted and ignored. Matching is case insensitive
+    /// and '_' is accepted as a separator in place of '-'.
+    ///
+    /// Tags for languages without tailored casing rules, as well as empty or malformed tags, fall
+    /// back to [`Locale::Root`].
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::Locale;
+    /// assert_eq!(Locale::from_language_tag("tr-TR"), Locale::Turkish);
+    /// assert_eq!(Locale::from_language_tag("az-Latn-AZ"), Locale::Azeri);
+    /// assert_eq!(Locale::from_language_tag("lt"), Locale::Lithuanian);
+    /// assert_eq!(Locale::from_language_tag("nl-BE"), Locale::Dutch);
+    /// assert_eq!(Locale::from_language_tag("ga-IE"), Locale::Irish);
+    /// assert_eq!(Locale::from_language_tag("el-GR"), Locale::Greek);
+    /// assert_eq!(Locale::from_language_tag("de-DE"), Locale::Root);
+    /// assert_eq!(Locale::from_language_tag("hy-AM"), Locale::Armenian);
+    /// assert_eq!(Locale::from_language_tag("hyw"), Locale::Root);
+    /// assert_eq!(Locale::from_language_tag("TR_tr"), Locale::Turkish);
+    ///
+    /// assert_eq!(Locale::from_language_tag("en-US"), Locale::Root);
+    /// assert_eq!(Locale::from_language_tag("trv"), Locale::Root);
+    /// assert_eq!(Locale::from_language_tag(""), Locale::Root);
+    /// ```
+    #[must_use]
+    pub fn from_language_tag(tag: &str) -> Locale {
+        let language = tag.split(['-', '_']).next().unwrap_or_default();
+        if language.eq_ignore_ascii_case("tr") {
+            Locale::Turkish
+        } else if language.eq_ignore_ascii_case("az") {
+            Locale::Azeri
+        } else if language.eq_ignore_ascii_case("lt") {
+            Locale::Lithuanian
+        } else if language.eq_ignore_ascii_case("nl") {
+            Locale::Dutch
+        } else if language.eq_ignore_ascii_case("ga") {
+            Locale::Irish
+        } else if language.eq_ignore_ascii_case("el") {
+            Locale::Greek
+        } else if language.eq_ignore_ascii_case("hy") {
+            Locale::Armenian
+        } else {
+            Locale::Root
+        }
+    }
+
+    /// Titlecases the first char of `s` into `out`. In Dutch an initial 'ij' is titlecased as a
+    /// unit, and in Irish the prefix of an initial mutation is kept lowercase and the letter after it
+    /// is titlecased instead. Returns the byte index in `s` where the untouched rest of the string
+    /// starts.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn push_titlecase_first(self, out: &mut String, s: &str) -> usize {
+        match self {
+            Locale::Lithuanian => return push_titlecase_first_lt(out, s),
+            Locale::Dutch => {
+                if let Some(end) = ij_digraph_len(s) {
+                    out.extend(s[..end - 1].chars().flat_map(char::to_uppercase));
+                    out.push('\u{004A}');
+                    return end;
+                }
+            }
+            Locale::Irish => {
+                if let Some((prefix, letter)) = mutation_prefix(s) {
+                    out.push_str(&s[..prefix]);
+                    return letter + Locale::Root.push_titlecase_first(out, &s[letter..]);
+                }
+            }
+            _ => {}
+        }
+        s.chars().next().map_or(0, |first| {
+            out.extend(ToTitleCase(CaseMappingIter::new(to_titlecase_in(first, self))));
+            first.len_utf8()
+        })
+    }
+
+    /// Returns the byte index in `s` where the untouched rest of the string starts if
+    /// [`Locale::push_titlecase_first`] would copy the start of `s` unchanged, or `None` if it would
+    /// change it.
+    pub(crate) fn titlecase_first_unchanged(self, s: &str) -> Option<usize> {
+        let Some(first) = s.chars().next() else {
+            return Some(0);
+        };
+        match self {
+            Locale::Lithuanian => return titlecase_first_lt_unchanged(s),
+            Locale::Dutch => {
+                if let Some(end) = ij_digraph_len(s) {
+                    let unchanged = s[..end - 1].chars().all(|c| uppercases_to_self(c, self))
+                        && s.as_bytes()[end - 1] == b'J';
+                    return unchanged.then_some(end);
+                }
+            }
+            Locale::Irish => {
+                if let Some((prefix, letter)) = mutation_prefix(s) {
+                    if prefix != letter {
+                        return None;
+                    }
+                    return Locale::Root
+                        .titlecase_first_unchanged(&s[letter..])
+                        .map(|rest| letter + rest);
+                }
+            }
+            _ => {}
+        }
+        (to_titlecase_in(first, self) == [first, '\0', '\0']).then_some(first.len_utf8())
+    }
+
+    /// Splits `s` at the word boundaries from [UAX #29](https://www.unicode.org/reports/tr29/) and
+    /// returns each word with the byte index it starts at. In Irish the hyphenated prefixes "n-" and
+    /// "t-" are joined to the word after them, so that "t-uisce" is titlecased as one word.
+    pub(crate) fn split_words(self, s: &str) -> Words<'_> {
+        Words {
+            s,
+            locale: self,
+            bounds: s.split_word_bound_indices(),
+        }
+    }
+
+    /// Lowercases the chars of `s` in `range` into `out`, using the whole of `s` as context. Runs of
+    /// ASCII are lowercased a byte at a time, except for the letters with locale specific rules.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn push_lowercase(self, out: &mut String, s: &str, range: Range<usize>) {
+        let special: &[u8] = match self {
+            Locale::Turkish | Locale::Azeri => b"I",
+            Locale::Lithuanian => b"IJ",
+            Locale::Root
+            | Locale::Dutch
+            | Locale::Irish
+            | Locale::Greek
+            | Locale::GermanCapitalSharpS
+            | Locale::Armenian => b"",
+        };
+        push_mapped(
+            out,
+            s,
+            range,
+            special,
+            str::make_ascii_lowercase,
+            |out, range| match self {
+                Locale::Turkish | Locale::Azeri => push_lowercase_tr_az(out, s, range),
+                Locale::Lithuanian => push_lowercase_lt(out, s, range),
+                Locale::Root
+                | Locale::Dutch
+                | Locale::Irish
+                | Locale::Greek
+                | Locale::GermanCapitalSharpS
+                | Locale::Armenian => {
+                    push_lowercase(out, s, range, char::to_lowercase);
+                }
+            },
+        );
+    }
+
+    /// Uppercases the chars of `s` in `range` into `out`, using the whole of `s` as context. Runs of
+    /// ASCII are uppercased a byte at a time, except for the letters with locale specific rules.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn push_uppercase(self, out: &mut String, s: &str, range: Range<usize>) {
+        let special: &[u8] = match self {
+            Locale::Turkish | Locale::Azeri => b"i",
+            _ => b"",
+        };
+        push_mapped(
+            out,
+            s,
+            range,
+            special,
+            str::make_ascii_uppercase,
+            |out, range| match self {
+                Locale::Turkish | Locale::Azeri => {
+                    out.extend(s[range].chars().flat_map(to_uppercase_tr_or_az));
+                }
+                Locale::Lithuanian => push_without_soft_dots(out, s, range, char::to_uppercase),
+                Locale::Greek => push_uppercase_el(out, s, range),
+                Locale::GermanCapitalSharpS => {
+                    for c in s[range].chars() {
+                        match c {
+                            '\u{00DF}' => out.push('\u{1E9E}'),
+                            _ => out.extend(c.to_uppercase()),
+                        }
+                    }
+                }
+                Locale::Armenian => {
+                    for c in s[range].chars() {
+                        match c {
+                            '\u{0587}' => out.push_str("\u{0535}\u{054E}"),
+                            _ => out.extend(c.to_uppercase()),
+                        }
+                    }
+                }
+                Locale::Root | Locale::Dutch | Locale::Irish => {
+                    out.extend(s[range].chars().flat_map(char::to_uppercase));
+                }
+            },
+        );
+    }
+
+    /// Returns true if [`Locale::push_uppercase`] would copy all of `s` unchanged. In Greek this can
+    /// return false for text that would not change, such as a standalone 'Ή', but never returns true
+    /// for text that would.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn uppercase_is_unchanged(self, s: &str) -> bool {
+        match self {
+            Locale::Lithuanian => uppercase_lt_is_unchanged(s),
+            Locale::Greek => uppercase_el_is_unchanged(s),
+            _ => s.chars().all(|c| uppercases_to_self(c, self)),
+        }
+    }
+}
+
+/// The words of a str, with the byte index each one starts at. See [`Locale::split_words`].
+pub(crate) struct Words<'a> {
+    s: &'a str,
+    locale: Locale,
+    bounds: UWordBoundIndices<'a>,
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, word) = self.bounds.next()?;
+        if self.locale == Locale::Irish
+            && matches!(word, "n" | "t")
+            && mutation_prefix(&self.s[start..]).is_some()
+        {
+            // The prefix is followed by a hyphen and the word it is joined to.
+            self.bounds.next();
+            if let Some((after, next)) = self.bounds.next() {
+                return Some((start, &self.s[start..after + next.len()]));
+            }
+        }
+        Some((start, word))
+    }
+}
+
+/// Returns the byte length of the Dutch 'ij' digraph at the start of `s`, up to and including the
+/// 'j'. The letters may be in any case, and the 'i' may carry an accent as in "íjs", precomposed or
+/// followed by combining marks. Returns `None` if `s` does not start with the digraph. The
+/// precomposed 'ĳ' needs no special handling, since it already titlecases to 'Ĳ'.
+fn ij_digraph_len(s: &str) -> Option<usize> {
+    let first = s.chars().next().filter(|c| matches!(c, 'i' | 'I' | 'í' | 'Í'))?;
+    let (i, j) = s[first.len_utf8()..]
+        .char_indices()
+        .find(|&(_, c)| combining_class(c) == 0)?;
+    matches!(j, 'j' | 'J').then_some(first.len_utf8() + i + j.len_utf8())
+}
+
+/// The Irish eclipsis prefixes with the letter each one comes before, and the 't' put before 's'.
+const IRISH_PREFIXES: &[(&str, char)] = &[
+    ("bh", 'f'),
+    ("m", 'b'),
+    ("g", 'c'),
+    ("n", 'd'),
+    ("n", 'g'),
+    ("b", 'p'),
+    ("d", 't'),
+    ("t", 's'),
+];
+
+/// Returns true if `c` is an Irish vowel, with or without a fada, in either case.
+fn is_irish_vowel(c: char) -> bool {
+    matches!(
+        c,
+        'a' | 'e' | 'i' | 'o' | 'u' | 'á' | 'é' | 'í' | 'ó' | 'ú'
+            | 'A' | 'E' | 'I' | 'O' | 'U' | 'Á' | 'É' | 'Í' | 'Ó' | 'Ú'
+    )
+}
+
+/// Returns the byte length of the Irish initial mutation prefix at the start of `s`, and the byte
+/// index of the letter after it, which is the one that is titlecased. The prefixes are eclipsis as
+/// in "ngaeilge", 't' before 's' as in "tsráid", 'n' or 't' before a vowel, either hyphenated as in
+/// "t-uisce" or with the vowel already capitalized as in "nAthair", and 'h' before a capitalized
+/// vowel as in "hÉireann". The hyphen is dropped, since it is only written before a lowercase vowel.
+/// An 'h' before a lowercase vowel is not a prefix, since it can be the start of a word like "hata".
+///
+/// Only lowercase prefixes are recognized, because in all caps text they cannot be told apart from
+/// the start of the word. Returns `None` if `s` does not start with a prefix.
+fn mutation_prefix(s: &str) -> Option<(usize, usize)> {
+    let eclipsis = IRISH_PREFIXES.iter().find(|&&(prefix, letter)| {
+        s.strip_prefix(prefix)
+            .and_then(|rest| rest.chars().next())
+            .is_some_and(|c| c.to_ascii_lowercase() == letter)
+    });
+    if let Some(&(prefix, _)) = eclipsis {
+        return Some((prefix.len(), prefix.len()));
+    }
+    let rest = s.get(1..)?;
+    let (letter, vowel) = match rest.strip_prefix('-') {
+        Some(after) => (2, after.chars().next()?),
+        None => (1, rest.chars().next()?),
+    };
+    let prefixed = match s.as_bytes()[0] {
+        b'h' => letter == 1 && vowel.is_uppercase(),
+        b'n' | b't' => letter == 2 || vowel.is_uppercase(),
+        _ => false,
+    };
+    (prefixed && is_irish_vowel(vowel)).then_some((1, letter))
+}
+
+/// Accepts a char and a [`Locale`] and returns the Unicode title case for that character in the
+/// locale as a 3 char array.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::{to_titlecase_in, Locale};
+/// assert_eq!(to_titlecase_in('i', Locale::Root), ['I', '\0', '\0']);
+/// assert_eq!(to_titlecase_in('i', Locale::Turkish), ['İ', '\0', '\0']);
+/// assert_eq!(to_titlecase_in('ﬄ', Locale::Azeri), ['F', 'f', 'l']);
+/// assert_eq!(to_titlecase_in('ǆ', Locale::Lithuanian), ['ǅ', '\0', '\0']);
+/// assert_eq!(to_titlecase_in('ß', Locale::Root), ['S', 's', '\0']);
+/// assert_eq!(to_titlecase_in('ß', Locale::GermanCapitalSharpS), ['ẞ', '\0', '\0']);
+/// assert_eq!(to_titlecase_in('և', Locale::Root), ['Ե', 'ւ', '\0']);
+/// assert_eq!(to_titlecase_in('և', Locale::Armenian), ['Ե', 'վ', '\0']);
+/// ```
+#[must_use]
+pub fn to_titlecase_in(c: char, locale: Locale) -> [char; 3] {
+    match locale {
+        Locale::Turkish | Locale::Azeri if c == '\u{0069}' => ['\u{0130}', '\0', '\0'],
+        Locale::GermanCapitalSharpS if c == '\u{00DF}' => ['\u{1E9E}', '\0', '\0'],
+        Locale::Armenian if c == '\u{0587}' => ['\u{0535}', '\u{057E}', '\0'],
+        _ => to_titlecase(c),
+    }
+}
+
+/// Trait to add lower and upper case operations in a [`Locale`] to strings and string slices. For
+/// titlecasing in a locale see the `_in` functions of [`crate::StrTitleCase`]. Requires the `alloc`
+/// feature.
+#[cfg(feature = "alloc")]
+pub trait StrLocaleCasing {
+    /// Returns the Unicode lower case of this str in the given locale as a new String.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::{Locale, StrLocaleCasing};
+    /// assert_eq!("KIRMIZI".to_lowercase_in(Locale::Root), "kirmizi");
+    /// assert_eq!("KIRMIZI".to_lowercase_in(Locale::Turkish), "kırmızı");
+    /// assert_eq!("I\u{0307}ZMI\u{0307}R".to_lowercase_in(Locale::Turkish), "izmir");
+    /// assert_eq!("ÌS".to_lowercase_in(Locale::Lithuanian), "i\u{0307}\u{0300}s");
+    /// assert_eq!("ΟΔΟΣ".to_lowercase_in(Locale::Root), "οδος");
+    /// ```
+    fn to_lowercase_in(&self, locale: Locale) -> String;
+
+    /// Returns the Unicode upper case of this str in the given locale as a new String.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::{Locale, StrLocaleCasing};
+    /// assert_eq!("istanbul".to_uppercase_in(Locale::Root), "ISTANBUL");
+    /// assert_eq!("istanbul".to_uppercase_in(Locale::Azeri), "İSTANBUL");
+    /// assert_eq!("i\u{0307}\u{0300}s".to_uppercase_in(Locale::Lithuanian), "I\u{0300}S");
+    /// assert_eq!("Καλημέρα".to_uppercase_in(Locale::Greek), "ΚΑΛΗΜΕΡΑ");
+    /// assert_eq!("Straße".to_uppercase_in(Locale::Root), "STRASSE");
+    /// assert_eq!("Straße".to_uppercase_in(Locale::GermanCapitalSharpS), "STRAẞE");
+    /// assert_eq!("Երևան".to_uppercase_in(Locale::Root), "ԵՐԵՒԱՆ");
+    /// assert_eq!("Երևան".to_uppercase_in(Locale::Armenian), "ԵՐԵՎԱՆ");
+    /// ```
+    fn to_uppercase_in(&self, locale: Locale) -> String;
+
+    /// Like [`StrLocaleCasing::to_lowercase_in`], but borrows this str instead of allocating when it
+    /// would be returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::{Locale, StrLocaleCasing};
+    /// assert!(matches!("kırmızı".to_lowercase_in_cow(Locale::Turkish), Cow::Borrowed(_)));
+    /// assert_eq!("KIRMIZI".to_lowercase_in_cow(Locale::Turkish), "kırmızı");
+    /// assert!(matches!("i\u{0307}\u{0300}s".to_lowercase_in_cow(Locale::Lithuanian), Cow::Borrowed(_)));
+    /// ```
+    fn to_lowercase_in_cow(&self, locale: Locale) -> Cow<'_, str>;
+
+    /// Like [`StrLocaleCasing::to_uppercase_in`], but borrows this str instead of allocating when it
+    /// would be returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::{Locale, StrLocaleCasing};
+    /// assert!(matches!("İSTANBUL".to_uppercase_in_cow(Locale::Azeri), Cow::Borrowed(_)));
+    /// assert_eq!("istanbul".to_uppercase_in_cow(Locale::Azeri), "İSTANBUL");
+    /// assert!(matches!("ΚΑΛΗΜΕΡΑ".to_uppercase_in_cow(Locale::Greek), Cow::Borrowed(_)));
+    /// assert_eq!("ΚΑΛΗΜΈΡΑ".to_uppercase_in_cow(Locale::Greek), "ΚΑΛΗΜΕΡΑ");
+    /// ```
+    fn to_uppercase_in_cow(&self, locale: Locale) -> Cow<'_, str>;
+}
+
+#[cfg(feature = "alloc")]
+impl StrLocaleCasing for str {
+    fn to_lowercase_in(&self, locale: Locale) -> String {
+        let mut result = String::with_capacity(self.len());
+        locale.push_lowercase(&mut result, self, 0..self.len());
+        result
+    }
+
+    fn to_uppercase_in(&self, locale: Locale) -> String {
+        let mut result = String::with_capacity(self.len());
+        locale.push_uppercase(&mut result, self, 0..self.len());
+        result
+    }
+
+    fn to_lowercase_in_cow(&self, locale: Locale) -> Cow<'_, str> {
+        let unchanged = self.chars().all(|c| lowercases_to_self(c, locale));
+        borrow_if_unchanged(self, unchanged, |s| s.to_lowercase_in(locale))
+    }
+
+    fn to_uppercase_in_cow(&self, locale: Locale) -> Cow<'_, str> {
+        let unchanged = locale.uppercase_is_unchanged(self);
+        borrow_if_unchanged(self, unchanged, |s| s.to_uppercase_in(locale))
+    }
+}
@@ -0,0 +1,451 @@
+//! A module to supply Lithuanian (LT) locale specific upper, lower and title case utilities.
+//!
+//! Lithuanian keeps the dot of 'i' and 'j' when they carry another accent above. Unicode special
+//! casing has three rules for this:
+//!   1) `More_Above`: when 'I', 'J' or 'Į' is followed by an accent above, lowercasing it adds an
+//!      explicit U+0307 COMBINING DOT ABOVE. 'Ì', 'Í' and 'Ĩ' always lowercase with the dot.
+//!   2) `After_Soft_Dotted`: a U+0307 COMBINING DOT ABOVE that follows a soft dotted char such as 'i'
+//!      or 'j' is removed when upper or titlecasing, since the capital letter has no dot.
+//!   3) Everything else follows the locale agnostic Unicode mappings.
+//!
+//! The contextual rules need to see the chars around the one being mapped, so only the string
+//! functions apply them. The char functions apply the rules that need no context.
+//!
+//! ```
+//! # #[cfg(feature = "alloc")] {
+//! use unicode_titlecase::lt::StrLtCasing;
+//! assert_eq!("ÌS".to_lowercase_lt(), "i\u{0307}\u{0300}s");
+//! assert_eq!("i\u{0307}\u{0300}s".to_uppercase_lt(), "I\u{0300}S");
+//! assert_eq!("j\u{0307}\u{0301}ūra".to_titlecase_lt(), "J\u{0301}ūra");
+//! # }
+//! ```
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt::{Display, Formatter, Result};
+use core::iter::FusedIterator;
+#[cfg(feature = "alloc")]
+use core::ops::Range;
+
+use crate::{combining_class, to_titlecase, CaseMappingIter, TitleCase, ToTitleCase};
+#[cfg(feature = "alloc")]
+use crate::{is_final_sigma, Locale, StrLocaleCasing, StrTitleCase};
+
+/// The chars with the Unicode `Soft_Dotted` property. These lose their dot when an accent is placed
+/// above them.
+const SOFT_DOTTED: &[char] = &[
+    '\u{0069}', '\u{006A}', '\u{012F}', '\u{0249}', '\u{0268}', '\u{029D}', '\u{02B2}', '\u{03F3}',
+    '\u{0456}', '\u{0458}', '\u{1D62}', '\u{1D96}', '\u{1DA4}', '\u{1DA8}', '\u{1E2D}', '\u{1ECB}',
+    '\u{2071}', '\u{2148}', '\u{2149}', '\u{2C7C}', '\u{1D422}', '\u{1D423}', '\u{1D456}',
+    '\u{1D457}', '\u{1D48A}', '\u{1D48B}', '\u{1D4BE}', '\u{1D4BF}', '\u{1D4F2}', '\u{1D4F3}',
+    '\u{1D526}', '\u{1D527}', '\u{1D55A}', '\u{1D55B}', '\u{1D58E}', '\u{1D58F}', '\u{1D5C2}',
+    '\u{1D5C3}', '\u{1D5F6}', '\u{1D5F7}', '\u{1D62A}', '\u{1D62B}', '\u{1D65E}', '\u{1D65F}',
+    '\u{1D692}', '\u{1D693}', '\u{1DF1A}', '\u{1E04C}', '\u{1E04D}', '\u{1E068}',
+];
+
+const COMBINING_DOT_ABOVE: char = '\u{0307}';
+
+/// The canonical combining class of accents placed above a letter.
+const ABOVE: u8 = 230;
+
+/// Returns true if a combining char of class [`ABOVE`] follows at the start of `after` with no
+/// intervening char of class 0 or [`ABOVE`].
+#[cfg(feature = "alloc")]
+fn is_more_above(after: &str) -> bool {
+    after
+        .chars()
+        .map(combining_class)
+        .find(|&ccc| ccc == 0 || ccc == ABOVE)
+        .is_some_and(|ccc| ccc == ABOVE)
+}
+
+/// Returns true if a soft dotted char precedes the end of `before` with no intervening char of
+/// class 0 or [`ABOVE`].
+fn is_after_soft_dotted(before: &str) -> bool {
+    before
+        .chars()
+        .rev()
+        .find(|&c| SOFT_DOTTED.contains(&c) || matches!(combining_class(c), 0 | ABOVE))
+        .is_some_and(|c| SOFT_DOTTED.contains(&c))
+}
+
+/// Lowercases the chars of `s` in `range` and appends them to `out`, using the whole of `s` as
+/// context for the `More_Above` and `Final_Sigma` rules.
+#[cfg(feature = "alloc")]
+pub(crate) fn push_lowercase_lt(out: &mut String, s: &str, range: Range<usize>) {
+    let start = range.start;
+    for (i, c) in s[range].char_indices() {
+        let after = &s[start + i + c.len_utf8()..];
+        match c {
+            '\u{0049}' | '\u{004A}' | '\u{012E}' if is_more_above(after) => {
+                out.extend(c.to_lowercase());
+                out.push(COMBINING_DOT_ABOVE);
+            }
+            '\u{03A3}' if is_final_sigma(s, start + i) => out.push('\u{03C2}'),
+            _ => out.extend(to_lowercase_lt(c)),
+        }
+    }
+}
+
+/// Appends the chars of `s` in `range` to `out` with `map`, dropping any U+0307 COMBINING DOT ABOVE
+/// that follows a soft dotted char. This is the `After_Soft_Dotted` rule for upper and titlecasing.
+#[cfg(feature = "alloc")]
+pub(crate) fn push_without_soft_dots<I: Iterator<Item = char>>(
+    out: &mut String,
+    s: &str,
+    range: Range<usize>,
+    map: impl Fn(char) -> I,
+) {
+    let start = range.start;
+    for (i, c) in s[range].char_indices() {
+        if c != COMBINING_DOT_ABOVE || !is_after_soft_dotted(&s[..start + i]) {
+            out.extend(map(c));
+        }
+    }
+}
+
+/// Titlecases the first char of `s` into `out` and drops the dot above from it if it is soft dotted.
+/// Returns the byte index of the first char after the titlecased letter and its combining marks.
+#[cfg(feature = "alloc")]
+pub(crate) fn push_titlecase_first_lt(out: &mut String, s: &str) -> usize {
+    let Some(first) = s.chars().next() else {
+        return 0;
+    };
+    out.extend(first.to_titlecase_lt());
+    let marks_end = marks_end(s, first.len_utf8());
+    push_without_soft_dots(out, s, first.len_utf8()..marks_end, core::iter::once);
+    marks_end
+}
+
+/// Returns the byte index of the first char of class 0 in `s` at or after `start`, or the length of
+/// `s` if there is none.
+fn marks_end(s: &str, start: usize) -> usize {
+    s[start..]
+        .char_indices()
+        .find(|&(_, c)| combining_class(c) == 0)
+        .map_or(s.len(), |(i, _)| start + i)
+}
+
+/// Returns the byte index of the first char after the first letter of `s` and its combining marks if
+/// [`push_titlecase_first_lt`] would copy them unchanged, or `None` if it would change them.
+pub(crate) fn titlecase_first_lt_unchanged(s: &str) -> Option<usize> {
+    let Some(first) = s.chars().next() else {
+        return Some(0);
+    };
+    let marks_start = first.len_utf8();
+    let marks_end = marks_end(s, marks_start);
+    let drops_dot = s[marks_start..marks_end]
+        .char_indices()
+        .any(|(i, c)| c == COMBINING_DOT_ABOVE && is_after_soft_dotted(&s[..marks_start + i]));
+    (to_titlecase_lt(first) == [first, '\0', '\0'] && !drops_dot).then_some(marks_end)
+}
+
+/// Returns true if uppercasing `s` in the LT locale returns it unchanged.
+#[cfg(feature = "alloc")]
+pub(crate) fn uppercase_lt_is_unchanged(s: &str) -> bool {
+    s.char_indices().all(|(i, c)| {
+        let mut upper = c.to_uppercase();
+        upper.len() == 1
+            && upper.next() == Some(c)
+            && (c != COMBINING_DOT_ABOVE || !is_after_soft_dotted(&s[..i]))
+    })
+}
+
+/// Accepts a char and returns the Unicode lower case in the LT locale for that character as an
+/// iterator. The accented capital I's keep their dot.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::lt::to_lowercase_lt;
+/// assert_eq!(to_lowercase_lt('Ì').to_string(), "i\u{0307}\u{0300}");
+/// assert_eq!(to_lowercase_lt('Í').to_string(), "i\u{0307}\u{0301}");
+/// assert_eq!(to_lowercase_lt('Ĩ').to_string(), "i\u{0307}\u{0303}");
+/// assert_eq!(to_lowercase_lt('Ą').to_string(), "ą");
+/// ```
+/// # Context
+/// 'I', 'J' and 'Į' only gain a dot when followed by another accent above. That needs the
+/// following chars, so it is only applied by [`StrLtCasing::to_lowercase_lt`].
+#[must_use]
+pub fn to_lowercase_lt(c: char) -> LtCaseMapper {
+    match c {
+        '\u{00CC}' => LtCaseMapper::new(['\u{0069}', COMBINING_DOT_ABOVE, '\u{0300}']),
+        '\u{00CD}' => LtCaseMapper::new(['\u{0069}', COMBINING_DOT_ABOVE, '\u{0301}']),
+        '\u{0128}' => LtCaseMapper::new(['\u{0069}', COMBINING_DOT_ABOVE, '\u{0303}']),
+        _ => LtCaseMapper::from_iter(c.to_lowercase()),
+    }
+}
+
+/// Accepts a char and returns the Unicode upper case in the LT locale for that character as an
+/// iterator.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::lt::to_uppercase_lt;
+/// assert_eq!(to_uppercase_lt('į').to_string(), "Į");
+/// assert_eq!(to_uppercase_lt('ė').to_string(), "Ė");
+/// assert_eq!(to_uppercase_lt('ﬀ').to_string(), "FF");
+/// ```
+/// # Context
+/// A U+0307 COMBINING DOT ABOVE is only removed when it follows a soft dotted char. That needs the
+/// preceding chars, so it is only applied by [`StrLtCasing::to_uppercase_lt`].
+#[must_use]
+pub fn to_uppercase_lt(c: char) -> LtCaseMapper {
+    LtCaseMapper::from_iter(c.to_uppercase())
+}
+
+/// Accepts a char and returns the Unicode title case in the LT locale for that character as a 3 char
+/// array.
+///
+/// # Examples
+/// ```
+/// use unicode_titlecase::lt::to_titlecase_lt;
+/// assert_eq!(to_titlecase_lt('į'), ['Į', '\0', '\0']);
+/// assert_eq!(to_titlecase_lt('ǆ'), ['ǅ', '\0', '\0']);
+/// assert_eq!(to_titlecase_lt('ﬄ'), ['F', 'f', 'l']);
+/// ```
+/// # Context
+/// A U+0307 COMBINING DOT ABOVE is only removed when it follows a soft dotted char. That needs the
+/// preceding chars, so it is only applied by [`StrLtCasing::to_titlecase_lt`].
+#[must_use]
+pub fn to_titlecase_lt(c: char) -> [char; 3] {
+    to_titlecase(c)
+}
+
+/// This trait provides functions to perform lower, upper and title case transformations on a char
+/// in the LT locale.
+pub trait LtCasing {
+    /// Returns the Unicode lower case of this char in the LT locale as an iterator.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::lt::LtCasing;
+    /// assert_eq!('Ì'.to_lowercase_lt().to_string(), "i\u{0307}\u{0300}");
+    /// assert_eq!('Ų'.to_lowercase_lt().to_string(), "ų");
+    /// ```
+    fn to_lowercase_lt(self) -> LtCaseMapper;
+
+    /// Returns the Unicode upper case of this char in the LT locale as an iterator.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::lt::LtCasing;
+    /// assert_eq!('į'.to_uppercase_lt().to_string(), "Į");
+    /// assert_eq!('š'.to_uppercase_lt().to_string(), "Š");
+    /// ```
+    fn to_uppercase_lt(self) -> LtCaseMapper;
+
+    /// Returns the Unicode title case of this char in the LT locale as an iterator.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::lt::LtCasing;
+    /// assert_eq!('į'.to_titlecase_lt().to_string(), "Į");
+    /// assert_eq!('ﬄ'.to_titlecase_lt().to_string(), "Ffl");
+    /// ```
+    fn to_titlecase_lt(self) -> ToTitleCase;
+}
+
+impl LtCasing for char {
+    fn to_lowercase_lt(self) -> LtCaseMapper {
+        to_lowercase_lt(self)
+    }
+
+    fn to_uppercase_lt(self) -> LtCaseMapper {
+        to_uppercase_lt(self)
+    }
+
+    fn to_titlecase_lt(self) -> ToTitleCase {
+        self.to_titlecase()
+    }
+}
+
+/// This trait provides functions to perform lower, upper and title case transformations on a str
+/// in the LT locale. Unlike the char functions, these apply the contextual `More_Above` and
+/// `After_Soft_Dotted` rules. Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+pub trait StrLtCasing {
+    /// Returns the Unicode lower case of this str in the LT locale as a new String.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::lt::StrLtCasing;
+    /// assert_eq!("ĮLANKA".to_lowercase_lt(), "įlanka");
+    /// assert_eq!("ÌS".to_lowercase_lt(), "i\u{0307}\u{0300}s");
+    /// ```
+    /// 'I', 'J' and 'Į' keep their dot when followed by another accent above:
+    /// ```
+    /// use unicode_titlecase::lt::StrLtCasing;
+    /// assert_eq!("I\u{0301}".to_lowercase_lt(), "i\u{0307}\u{0301}");
+    /// assert_eq!("J\u{0303}".to_lowercase_lt(), "j\u{0307}\u{0303}");
+    /// assert_eq!("Į\u{0301}".to_lowercase_lt(), "į\u{0307}\u{0301}");
+    /// assert_eq!("I\u{0328}".to_lowercase_lt(), "i\u{0328}");
+    /// ```
+    fn to_lowercase_lt(&self) -> String;
+
+    /// Returns the Unicode upper case of this str in the LT locale as a new String. A dot above that
+    /// follows a soft dotted char such as 'i' or 'j' is removed.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::lt::StrLtCasing;
+    /// assert_eq!("įlanka".to_uppercase_lt(), "ĮLANKA");
+    /// assert_eq!("i\u{0307}\u{0300}s".to_uppercase_lt(), "I\u{0300}S");
+    /// assert_eq!("į\u{0307}\u{0301}".to_uppercase_lt(), "Į\u{0301}");
+    /// assert_eq!("ė\u{0307}".to_uppercase_lt(), "Ė\u{0307}");
+    /// ```
+    fn to_uppercase_lt(&self) -> String;
+
+    /// Titlecases the first char of a string in the LT locale, leaves the rest unchanged, and returns
+    /// a copy. A dot above on a titlecased soft dotted char is removed.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::lt::StrLtCasing;
+    /// assert_eq!("vilnius".to_titlecase_lt(), "Vilnius");
+    /// assert_eq!("i\u{0307}\u{0300}s".to_titlecase_lt(), "I\u{0300}s");
+    /// assert_eq!("ji\u{0307}\u{0301}".to_titlecase_lt(), "Ji\u{0307}\u{0301}");
+    /// ```
+    fn to_titlecase_lt(&self) -> String;
+
+    /// Titlecases the first char of a string in the LT locale, lowercases the rest of the string, and
+    /// returns a copy.
+    ///
+    /// # Examples
+    /// ```
+    /// use unicode_titlecase::lt::StrLtCasing;
+    /// assert_eq!("ŠIAULIAI".to_titlecase_lt_lower_rest(), "Šiauliai");
+    /// assert_eq!("JÌS".to_titlecase_lt_lower_rest(), "Ji\u{0307}\u{0300}s");
+    /// assert_eq!("i\u{0307}\u{0300}S".to_titlecase_lt_lower_rest(), "I\u{0300}s");
+    /// ```
+    fn to_titlecase_lt_lower_rest(&self) -> String;
+
+    /// Like [`StrLtCasing::to_lowercase_lt`], but borrows this str instead of allocating when it
+    /// would be returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::lt::StrLtCasing;
+    /// assert!(matches!("i\u{0307}\u{0300}s".to_lowercase_lt_cow(), Cow::Borrowed(_)));
+    /// assert_eq!("ÌS".to_lowercase_lt_cow(), "i\u{0307}\u{0300}s");
+    /// ```
+    fn to_lowercase_lt_cow(&self) -> Cow<'_, str>;
+
+    /// Like [`StrLtCasing::to_uppercase_lt`], but borrows this str instead of allocating when it
+    /// would be returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::lt::StrLtCasing;
+    /// assert!(matches!("E\u{0307}".to_uppercase_lt_cow(), Cow::Borrowed(_)));
+    /// assert_eq!("i\u{0307}\u{0300}s".to_uppercase_lt_cow(), "I\u{0300}S");
+    /// ```
+    fn to_uppercase_lt_cow(&self) -> Cow<'_, str>;
+
+    /// Like [`StrLtCasing::to_titlecase_lt`], but borrows this str instead of allocating when it
+    /// would be returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::lt::StrLtCasing;
+    /// assert!(matches!("Vilnius".to_titlecase_lt_cow(), Cow::Borrowed(_)));
+    /// assert_eq!("i\u{0307}\u{0300}s".to_titlecase_lt_cow(), "I\u{0300}s");
+    /// ```
+    fn to_titlecase_lt_cow(&self) -> Cow<'_, str>;
+
+    /// Like [`StrLtCasing::to_titlecase_lt_lower_rest`], but borrows this str instead of allocating
+    /// when it would be returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unicode_titlecase::lt::StrLtCasing;
+    /// assert!(matches!("Ji\u{0307}\u{0300}s".to_titlecase_lt_lower_rest_cow(), Cow::Borrowed(_)));
+    /// assert_eq!("JÌS".to_titlecase_lt_lower_rest_cow(), "Ji\u{0307}\u{0300}s");
+    /// ```
+    fn to_titlecase_lt_lower_rest_cow(&self) -> Cow<'_, str>;
+}
+
+#[cfg(feature = "alloc")]
+impl StrLtCasing for str {
+    fn to_lowercase_lt(&self) -> String {
+        self.to_lowercase_in(Locale::Lithuanian)
+    }
+
+    fn to_uppercase_lt(&self) -> String {
+        self.to_uppercase_in(Locale::Lithuanian)
+    }
+
+    fn to_titlecase_lt(&self) -> String {
+        self.to_titlecase_in(Locale::Lithuanian)
+    }
+
+    fn to_titlecase_lt_lower_rest(&self) -> String {
+        self.to_titlecase_lower_rest_in(Locale::Lithuanian)
+    }
+
+    fn to_lowercase_lt_cow(&self) -> Cow<'_, str> {
+        self.to_lowercase_in_cow(Locale::Lithuanian)
+    }
+
+    fn to_uppercase_lt_cow(&self) -> Cow<'_, str> {
+        self.to_uppercase_in_cow(Locale::Lithuanian)
+    }
+
+    fn to_titlecase_lt_cow(&self) -> Cow<'_, str> {
+        self.to_titlecase_in_cow(Locale::Lithuanian)
+    }
+
+    fn to_titlecase_lt_lower_rest_cow(&self) -> Cow<'_, str> {
+        self.to_titlecase_lower_rest_in_cow(Locale::Lithuanian)
+    }
+}
+
+/// An iterator over a char that had its casing changed in the LT locale.
+///
+/// Copied from the std library's [`core::char::ToLowercase`] and [`core::char::ToUppercase`].
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct LtCaseMapper(CaseMappingIter);
+
+impl LtCaseMapper {
+    fn new(chars: [char; 3]) -> Self {
+        LtCaseMapper(CaseMappingIter::new(chars))
+    }
+
+    fn from_iter(mut chars: impl Iterator<Item = char>) -> Self {
+        LtCaseMapper::new([
+            chars.next().unwrap_or('\0'),
+            chars.next().unwrap_or('\0'),
+            chars.next().unwrap_or('\0'),
+        ])
+    }
+}
+
+impl Iterator for LtCaseMapper {
+    type Item = char;
+    fn next(&mut self) -> Option<char> {
+        self.0.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for LtCaseMapper {
+    fn next_back(&mut self) -> Option<char> {
+        self.0.next_back()
+    }
+}
+
+impl FusedIterator for LtCaseMapper {}
+
+impl ExactSizeIterator for LtCaseMapper {}
+
+impl Display for LtCaseMapper {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(&self.0, f)
+    }
+}
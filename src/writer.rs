@@ -0,0 +1,463 @@
+use core::fmt::{Result, Write};
+
+use crate::ascii::ascii_prefix_len;
+use crate::tr_az::{to_lowercase_tr_or_az, to_uppercase_tr_or_az};
+use crate::{
+    combining_class, is_case_ignorable, is_cased, to_titlecase_in, CaseMappingIter, Locale,
+};
+
+/// The most chars held back while waiting for the context of a contextual casing rule.
+const PENDING_CAPACITY: usize = 16;
+
+/// The canonical combining class of accents placed above a letter.
+const ABOVE: u8 = 230;
+
+/// The most bytes of ASCII mapped on the stack before they are passed to the wrapped writer.
+const ASCII_BLOCK: usize = 64;
+
+/// A char whose lower case depends on the chars that follow it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pending {
+    /// A 'Σ' after a cased char, which is final unless a cased char follows.
+    Sigma,
+    /// A TR/AZ 'I', which is the decomposed 'İ' if a U+0307 COMBINING DOT ABOVE follows.
+    CapitalI,
+}
+
+/// A casing operation applied by a [`TitleCaseWriter`].
+///
+/// New transforms may be added, so matches on this enum need a wildcard arm.
+///
+/// # Examples
+/// ```
+/// use core::fmt::Write;
+/// use unicode_titlecase::{CaseTransform, TitleCaseWriter};
+/// let mut writer = TitleCaseWriter::with_transform(String::new(), CaseTransform::UppercaseTrAz);
+/// writer.write_str("istanbul").unwrap();
+/// assert_eq!(writer.finish().unwrap(), "İSTANBUL");
+/// ```
+#[derive(Debug, Clone, Copy, Default, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum CaseTransform {
+    /// Titlecases the first char and leaves the rest unchanged, like
+    /// [`crate::StrTitleCase::to_titlecase`].
+    #[default]
+    Titlecase,
+    /// Titlecases the first char and lowercases the rest, like
+    /// [`crate::StrTitleCase::to_titlecase_lower_rest`].
+    TitlecaseLowerRest,
+    /// Titlecases the first char in the TR/AZ locale and leaves the rest unchanged, like
+    /// [`crate::StrTitleCase::to_titlecase_tr_or_az`].
+    TitlecaseTrOrAz,
+    /// Titlecases the first char and lowercases the rest in the TR/AZ locale, like
+    /// [`crate::StrTitleCase::to_titlecase_tr_or_az_lower_rest`].
+    TitlecaseTrOrAzLowerRest,
+    /// Lowercases every char in the TR/AZ locale, like
+    /// [`crate::tr_az::StrTrAzCasing::to_lowercase_tr_az`].
+    LowercaseTrAz,
+    /// Uppercases every char in the TR/AZ locale, like
+    /// [`crate::tr_az::StrTrAzCasing::to_uppercase_tr_az`].
+    UppercaseTrAz,
+}
+
+/// A [`core::fmt::Write`] adapter that titlecases the text written through it and passes it on to
+/// the wrapped writer. It supports the same modes as [`crate::StrTitleCase::to_titlecase`],
+/// [`crate::StrTitleCase::to_titlecase_lower_rest`] and their TR/AZ versions, as well as the other
+/// transforms in [`CaseTransform`]. It gives the same results no matter how the text is split
+/// between `write_str` calls.
+///
+/// In the lower rest modes some chars are held back until the chars after them are known, for
+/// example a 'Σ' that may end a word. Call [`TitleCaseWriter::finish`] after the last write to
+/// pass them on. If a held back char is followed by more than 16 combining marks, it is mapped as
+/// if the text ended there.
+///
+/// # Examples
+/// ```
+/// use core::fmt::Write;
+/// use unicode_titlecase::TitleCaseWriter;
+/// let mut writer = TitleCaseWriter::new_lower_rest(String::new());
+/// write!(writer, "ΟΔ").unwrap();
+/// write!(writer, "ΟΣ {}", "ΣΤΑΣΗΣ").unwrap();
+/// assert_eq!(writer.finish().unwrap(), "Οδος στασης");
+/// ```
+#[derive(Debug, Clone)]
+pub struct TitleCaseWriter<W: Write> {
+    inner: W,
+    transform: CaseTransform,
+    locale: Locale,
+    lower_rest: bool,
+    started: bool,
+    after_cased: bool,
+    pending: Option<Pending>,
+    marks: [char; PENDING_CAPACITY],
+    marks_len: usize,
+}
+
+impl<W: Write> TitleCaseWriter<W> {
+    /// Wraps a writer to apply the given transform to the text written.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::fmt::Write;
+    /// use unicode_titlecase::{CaseTransform, TitleCaseWriter};
+    /// let mut writer = TitleCaseWriter::with_transform(String::new(), CaseTransform::LowercaseTrAz);
+    /// writer.write_str("KIRMIZI I").unwrap();
+    /// writer.write_str("\u{0307}ZMİR").unwrap();
+    /// assert_eq!(writer.finish().unwrap(), "kırmızı izmir");
+    /// ```
+    pub fn with_transform(inner: W, transform: CaseTransform) -> Self {
+        let locale = match transform {
+            CaseTransform::Titlecase | CaseTransform::TitlecaseLowerRest => Locale::Root,
+            _ => Locale::Turkish,
+        };
+        let lower_rest = matches!(
+            transform,
+            CaseTransform::TitlecaseLowerRest
+                | CaseTransform::TitlecaseTrOrAzLowerRest
+                | CaseTransform::LowercaseTrAz
+        );
+        let titlecase_first = !matches!(
+            transform,
+            CaseTransform::LowercaseTrAz | CaseTransform::UppercaseTrAz
+        );
+        TitleCaseWriter {
+            inner,
+            transform,
+            locale,
+            lower_rest,
+            started: !titlecase_first,
+            after_cased: false,
+            pending: None,
+            marks: ['\0'; PENDING_CAPACITY],
+            marks_len: 0,
+        }
+    }
+
+    /// Wraps a writer to titlecase the first char written and leave the rest unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::fmt::Write;
+    /// use unicode_titlecase::TitleCaseWriter;
+    /// let mut writer = TitleCaseWriter::new(String::new());
+    /// writer.write_str("ﬄ").unwrap();
+    /// writer.write_str("OOR").unwrap();
+    /// assert_eq!(writer.finish().unwrap(), "FflOOR");
+    /// ```
+    pub fn new(inner: W) -> Self {
+        TitleCaseWriter::with_transform(inner, CaseTransform::Titlecase)
+    }
+
+    /// Wraps a writer to titlecase the first char written and lowercase the rest.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::fmt::Write;
+    /// use unicode_titlecase::TitleCaseWriter;
+    /// let mut writer = TitleCaseWriter::new_lower_rest(String::new());
+    /// writer.write_str("ǄUN").unwrap();
+    /// writer.write_str("GLA").unwrap();
+    /// assert_eq!(writer.finish().unwrap(), "ǅungla");
+    /// ```
+    pub fn new_lower_rest(inner: W) -> Self {
+        TitleCaseWriter::with_transform(inner, CaseTransform::TitlecaseLowerRest)
+    }
+
+    /// Wraps a writer to titlecase the first char written in the TR/AZ locale and leave the rest
+    /// unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::fmt::Write;
+    /// use unicode_titlecase::TitleCaseWriter;
+    /// let mut writer = TitleCaseWriter::new_tr_or_az(String::new());
+    /// writer.write_str("istanbul").unwrap();
+    /// assert_eq!(writer.finish().unwrap(), "İstanbul");
+    /// ```
+    pub fn new_tr_or_az(inner: W) -> Self {
+        TitleCaseWriter::with_transform(inner, CaseTransform::TitlecaseTrOrAz)
+    }
+
+    /// Wraps a writer to titlecase the first char written and lowercase the rest in the TR/AZ
+    /// locale.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::fmt::Write;
+    /// use unicode_titlecase::TitleCaseWriter;
+    /// let mut writer = TitleCaseWriter::new_tr_or_az_lower_rest(String::new());
+    /// writer.write_str("KIRMIZI I").unwrap();
+    /// writer.write_str("\u{0307}ZMIR").unwrap();
+    /// assert_eq!(writer.finish().unwrap(), "Kırmızı izmır");
+    /// ```
+    pub fn new_tr_or_az_lower_rest(inner: W) -> Self {
+        TitleCaseWriter::with_transform(inner, CaseTransform::TitlecaseTrOrAzLowerRest)
+    }
+
+    /// Returns a reference to the wrapped writer. Chars that are held back are not written to it
+    /// yet.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::fmt::Write;
+    /// use unicode_titlecase::TitleCaseWriter;
+    /// let mut writer = TitleCaseWriter::new_lower_rest(String::new());
+    /// writer.write_str("ΑΣ").unwrap();
+    /// assert_eq!(writer.get_ref(), "Α");
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the wrapped writer. Chars that are held back are not written
+    /// to it yet.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::fmt::Write;
+    /// use unicode_titlecase::TitleCaseWriter;
+    /// let mut writer = TitleCaseWriter::new(String::new());
+    /// writer.write_str("abc").unwrap();
+    /// writer.get_mut().clear();
+    /// writer.write_str("def").unwrap();
+    /// assert_eq!(writer.finish().unwrap(), "def");
+    /// ```
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Writes any chars that are held back, mapping them as if the text ended here, and returns the
+    /// wrapped writer.
+    ///
+    /// # Errors
+    /// Returns an error if the wrapped writer fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::fmt::Write;
+    /// use unicode_titlecase::TitleCaseWriter;
+    /// let mut writer = TitleCaseWriter::new_lower_rest(String::new());
+    /// writer.write_str("ΑΣ").unwrap();
+    /// assert_eq!(writer.finish().unwrap(), "Ας");
+    /// ```
+    pub fn finish(mut self) -> core::result::Result<W, core::fmt::Error> {
+        self.flush_pending()?;
+        Ok(self.inner)
+    }
+
+    /// Writes any chars that are held back, mapping them as if the text ended here.
+    pub(crate) fn flush_pending(&mut self) -> Result {
+        self.resolve(None).map(|_| ())
+    }
+
+    /// Returns the wrapped writer without writing the chars that are held back.
+    #[cfg(feature = "std")]
+    pub(crate) fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// The ASCII letters that the transform maps with a locale specific or contextual rule.
+    fn ascii_special(&self) -> &'static [u8] {
+        match self.transform {
+            CaseTransform::UppercaseTrAz => b"i",
+            _ if self.locale == Locale::Root => b"",
+            _ => b"I",
+        }
+    }
+
+    /// Writes a run of ASCII without any of the [`TitleCaseWriter::ascii_special`] letters, mapping
+    /// it a block of bytes at a time. Must only be called after the first char and with no pending
+    /// char.
+    fn write_ascii(&mut self, s: &str) -> Result {
+        let mut buf = [0; ASCII_BLOCK];
+        for block in s.as_bytes().chunks(ASCII_BLOCK) {
+            let buf = &mut buf[..block.len()];
+            buf.copy_from_slice(block);
+            if self.transform == CaseTransform::UppercaseTrAz {
+                buf.make_ascii_uppercase();
+            } else {
+                buf.make_ascii_lowercase();
+            }
+            self.inner
+                .write_str(core::str::from_utf8(buf).map_err(|_| core::fmt::Error)?)?;
+        }
+        if let Some(c) = s.chars().rev().find(|&c| !is_case_ignorable(c)) {
+            self.after_cased = is_cased(c);
+        }
+        Ok(())
+    }
+
+    fn write_lowercase(&mut self, c: char) -> Result {
+        match self.locale {
+            Locale::Turkish | Locale::Azeri => self.inner.write_char(to_lowercase_tr_or_az(c)),
+            _ => c.to_lowercase().try_for_each(|l| self.inner.write_char(l)),
+        }
+    }
+
+    /// Writes the pending char and the marks after it, now that `next` is known to follow them.
+    /// Returns true if `next` was consumed by a rule.
+    fn resolve(&mut self, next: Option<char>) -> core::result::Result<bool, core::fmt::Error> {
+        let mut consumed = false;
+        match self.pending.take() {
+            Some(Pending::Sigma) => {
+                let is_final = !next.is_some_and(is_cased);
+                self.inner
+                    .write_char(if is_final { '\u{03C2}' } else { '\u{03C3}' })?;
+            }
+            Some(Pending::CapitalI) => {
+                consumed = next == Some('\u{0307}');
+                self.inner
+                    .write_char(if consumed { '\u{0069}' } else { '\u{0131}' })?;
+            }
+            None => return Ok(false),
+        }
+        for i in 0..self.marks_len {
+            self.write_lowercase(self.marks[i])?;
+        }
+        self.marks_len = 0;
+        Ok(consumed)
+    }
+
+    fn write_lower_rest(&mut self, c: char) -> Result {
+        if let Some(pending) = self.pending {
+            let waiting = match pending {
+                Pending::Sigma => is_case_ignorable(c),
+                Pending::CapitalI => !matches!(combining_class(c), 0 | ABOVE),
+            };
+            if waiting && self.marks_len < PENDING_CAPACITY {
+                self.marks[self.marks_len] = c;
+                self.marks_len += 1;
+                return Ok(());
+            }
+            let next = if waiting { None } else { Some(c) };
+            if self.resolve(next)? {
+                return Ok(());
+            }
+        }
+        match c {
+            '\u{03A3}' if self.after_cased => self.pending = Some(Pending::Sigma),
+            '\u{0049}' if matches!(self.locale, Locale::Turkish | Locale::Azeri) => {
+                self.pending = Some(Pending::CapitalI);
+            }
+            _ => self.write_lowercase(c)?,
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for TitleCaseWriter<W> {
+    fn write_str(&mut self, mut s: &str) -> Result {
+        while let Some(c) = s.chars().next() {
+            if self.started && !self.lower_rest && self.transform != CaseTransform::UppercaseTrAz {
+                return self.inner.write_str(s);
+            }
+            let ascii_len = if self.started && self.pending.is_none() {
+                ascii_prefix_len(s, self.ascii_special())
+            } else {
+                0
+            };
+            if ascii_len > 0 {
+                self.write_ascii(&s[..ascii_len])?;
+                s = &s[ascii_len..];
+            } else {
+                self.write_char(c)?;
+                s = &s[c.len_utf8()..];
+            }
+        }
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> Result {
+        if !self.started {
+            self.started = true;
+            for t in CaseMappingIter::new(to_titlecase_in(c, self.locale)) {
+                self.inner.write_char(t)?;
+            }
+        } else if self.lower_rest {
+            self.write_lower_rest(c)?;
+        } else if self.transform == CaseTransform::UppercaseTrAz {
+            to_uppercase_tr_or_az(c).try_for_each(|u| self.inner.write_char(u))?;
+        } else {
+            self.inner.write_char(c)?;
+        }
+        if !is_case_ignorable(c) {
+            self.after_cased = is_cased(c);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::TitleCaseWriter;
+    use crate::StrTitleCase;
+    use alloc::string::String;
+    use core::fmt::Write;
+
+    const INPUTS: &[&str] = &[
+        "",
+        "ﬄOOR",
+        "ǄUNGLA ǄUNGLA",
+        "ΟΔΟΣ ΣΤΑΣΗΣ",
+        "ΑΣ'Α Α'Σ ΑΣ\u{0301}. ΑΣ\u{0301}Α",
+        "\u{0345}Σ",
+        "KIRMIZI I\u{0307}ZMI\u{0307}R",
+        "I\u{0316}\u{0307}I\u{0301}\u{0307}I",
+        "İSTANBUL İİ",
+        "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG, then the lazy dog sleeps. ΑΣ istanbul Is",
+    ];
+
+    type NewWriter = fn(String) -> TitleCaseWriter<String>;
+    type StrFunction = fn(&str) -> String;
+
+    fn write_split(new: NewWriter, s: &str, split: usize) -> String {
+        let mut writer = new(String::new());
+        writer.write_str(&s[..split]).unwrap();
+        writer.write_str(&s[split..]).unwrap();
+        writer.finish().unwrap()
+    }
+
+    #[test]
+    fn matches_tr_az_functions() {
+        use super::CaseTransform;
+        use crate::tr_az::StrTrAzCasing;
+
+        let transforms: [(CaseTransform, StrFunction); 2] = [
+            (CaseTransform::LowercaseTrAz, str::to_lowercase_tr_az),
+            (CaseTransform::UppercaseTrAz, str::to_uppercase_tr_az),
+        ];
+        for (transform, expected) in transforms {
+            for s in INPUTS {
+                for split in (0..=s.len()).filter(|&i| s.is_char_boundary(i)) {
+                    let mut writer = TitleCaseWriter::with_transform(String::new(), transform);
+                    writer.write_str(&s[..split]).unwrap();
+                    writer.write_str(&s[split..]).unwrap();
+                    assert_eq!(writer.finish().unwrap(), expected(s), "{s} at {split}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn matches_str_functions() {
+        let modes: [(NewWriter, StrFunction); 4] = [
+            (TitleCaseWriter::new, str::to_titlecase),
+            (
+                TitleCaseWriter::new_lower_rest,
+                str::to_titlecase_lower_rest,
+            ),
+            (TitleCaseWriter::new_tr_or_az, str::to_titlecase_tr_or_az),
+            (
+                TitleCaseWriter::new_tr_or_az_lower_rest,
+                str::to_titlecase_tr_or_az_lower_rest,
+            ),
+        ];
+        for (new, expected) in modes {
+            for s in INPUTS {
+                for split in (0..=s.len()).filter(|&i| s.is_char_boundary(i)) {
+                    assert_eq!(write_split(new, s, split), expected(s), "{s} at {split}");
+                }
+            }
+        }
+    }
+}